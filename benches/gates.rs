@@ -0,0 +1,147 @@
+use criterion::measurement::WallTime;
+use criterion::{
+    black_box, criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion,
+};
+use qudit_core::c64;
+use qudit_core::matrix::{Mat, MatVec};
+use qudit_core::{HasParams, QuditSystem};
+use qudit_expr::{DifferentiationLevel, Module, ModuleBuilder};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+use qudit_gates::{Gate, NativeUnitaryFn, QuditGate, TensorGate};
+
+/// Draw each of `num_params` parameters independently.
+fn random_params(num_params: usize) -> Vec<f64> {
+    (0..num_params).map(|_| rand::random::<f64>()).collect()
+}
+
+/// Benchmark evaluating the JIT-compiled unitary of `expr` in `group`.
+fn bench_expr_unitary(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: &str,
+    expr: UnitaryExpression,
+) {
+    let dim = expr.dimension();
+    let name = expr.name();
+    let params = random_params(expr.num_params());
+    let mut out_utry: Mat<c64> = Mat::zeros(dim, dim);
+
+    let module: Module<c64> = ModuleBuilder::new("bench", DifferentiationLevel::None)
+        .add_expression_with_stride(expr, out_utry.col_stride().try_into().unwrap())
+        .build();
+    let utry_func = module.get_function(&name).unwrap();
+    let out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(out_utry.as_mut()) };
+
+    group.bench_function(id, |b| {
+        b.iter(|| unsafe { utry_func.call(black_box(params.as_ptr()), out_ptr) })
+    });
+}
+
+/// Benchmark evaluating the JIT-compiled unitary and gradient of `expr` in
+/// `group`.
+fn bench_expr_gradient(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: &str,
+    expr: UnitaryExpression,
+) {
+    let dim = expr.dimension();
+    let name = expr.name();
+    let params = random_params(expr.num_params());
+    let mut out_utry: Mat<c64> = Mat::zeros(dim, dim);
+    let mut out_grad: MatVec<c64> = MatVec::zeros(dim, dim, expr.num_params());
+
+    let module: Module<c64> = ModuleBuilder::new("bench", DifferentiationLevel::Gradient)
+        .add_expression_with_stride(expr, out_utry.col_stride().try_into().unwrap())
+        .build();
+    let utry_and_grad_func = module.get_function_and_gradient(&name).unwrap();
+    let out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(out_utry.as_mut()) };
+    let out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(out_grad.as_mut()) };
+
+    group.bench_function(id, |b| {
+        b.iter(|| unsafe {
+            utry_and_grad_func.call(black_box(params.as_ptr()), out_ptr, out_grad_ptr)
+        })
+    });
+}
+
+/// The gates benchmarked for every radix.
+fn gates_with_radix(radix: usize) -> Vec<Gate> {
+    vec![
+        Gate::I(radix),
+        Gate::H(radix),
+        Gate::X(radix),
+        Gate::Z(radix),
+        Gate::P(radix),
+        Gate::RY(radix, (0, 1)),
+        Gate::CSUM(radix),
+        Gate::RZZ(radix),
+    ]
+}
+
+fn gate_evaluation(c: &mut Criterion) {
+    for radix in 2..=5 {
+        let gates = gates_with_radix(radix);
+
+        let mut group = c.benchmark_group(format!("unitary/{}", radix));
+        for gate in gates.iter() {
+            bench_expr_unitary(&mut group, &gate.name(), gate.gen_expr());
+        }
+        group.finish();
+
+        let mut group = c.benchmark_group(format!("gradient/{}", radix));
+        for gate in gates.iter().filter(|gate| gate.num_params() > 0) {
+            bench_expr_gradient(&mut group, &gate.name(), gate.gen_expr());
+        }
+        group.finish();
+    }
+
+    let mut group = c.benchmark_group("gradient/qubit");
+    bench_expr_gradient(&mut group, "U3", Gate::U3().gen_expr());
+    bench_expr_gradient(&mut group, "CP", Gate::CP().gen_expr());
+    group.finish();
+}
+
+fn fused_evaluation(c: &mut Criterion) {
+    let cnot_expr = Gate::CX().gen_expr();
+    let u3u3 = TensorGate::new(vec![Gate::U3(), Gate::U3()]).gen_expr();
+    let cnotu3u3 = u3u3.dot(&cnot_expr);
+    let triple = cnotu3u3.dot(&cnotu3u3).dot(&cnotu3u3).dot(&u3u3);
+
+    let mut group = c.benchmark_group("fusion");
+    bench_expr_gradient(&mut group, "U3xU3", u3u3);
+    bench_expr_gradient(&mut group, "(U3xU3).CNOT", cnotu3u3);
+    bench_expr_gradient(&mut group, "triple", triple);
+    group.finish();
+}
+
+fn native_evaluation(c: &mut Criterion) {
+    let mut gates = vec![Gate::U3(), Gate::CX(), Gate::CP()];
+    for radix in 2..=5 {
+        gates.push(Gate::H(radix));
+        gates.push(Gate::X(radix));
+        gates.push(Gate::P(radix));
+    }
+
+    let mut group = c.benchmark_group("native");
+    for gate in gates {
+        let native = gate.as_native().unwrap();
+        let dim = gate.dimension();
+        let params = random_params(gate.num_params());
+        let mut out_utry: Mat<c64> = Mat::zeros(dim, dim);
+        let mut out_grad: MatVec<c64> = MatVec::zeros(dim, dim, gate.num_params());
+
+        let id = BenchmarkId::new(gate.gen_expr().name(), dim);
+        group.bench_with_input(id, &params, |b, params| {
+            b.iter(|| {
+                native.write_unitary_and_gradient(
+                    black_box(params),
+                    out_utry.as_mut(),
+                    out_grad.as_mut(),
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, gate_evaluation, fused_evaluation, native_evaluation);
+criterion_main!(benches);
@@ -1,6 +1,10 @@
 use qudit_core::{HasParams, QuditRadices, QuditSystem, ToRadix};
 use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
 
+use crate::Gate;
+use crate::ParamSpec;
+use crate::QuditGate;
+
 
 /// An arbitrary controlled gate.
 ///
@@ -116,11 +120,25 @@ use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
 /// $$
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct ControlledGate {
+    // The gate being controlled.
+    gate: Box<Gate>,
+
+    // The number of levels for each control qudit.
+    control_radices: QuditRadices,
+
+    // The levels of the control qudits that activate the gate.
+    control_levels: Vec<Vec<usize>>,
+
+    // The expression of the controlled gate.
     expr: UnitaryExpression,
 }
 
 impl ControlledGate {
-    /// Construct a ControlledGate.
+    /// Construct a ControlledGate from any unitary expression generator.
+    ///
+    /// The generated expression is controlled as a `Gate::Expression`, so
+    /// the structure of `expr` is not kept. Use
+    /// [`ControlledGate::from_gate`] to control a [`Gate`] and keep it.
     ///
     /// # Arguments
     ///
@@ -129,6 +147,36 @@ impl ControlledGate {
     /// * `control_radixes` - The number of levels for each control qudit.
     ///
     /// * `control_levels` - The levels of the control qudits that activate the
+    ///   gate. See [`ControlledGate::from_gate`].
+    ///
+    /// # Returns
+    ///
+    /// A new ControlledGate.
+    ///
+    /// # Panics
+    ///
+    /// * Under the same conditions as [`ControlledGate::from_gate`].
+    pub fn new<E: UnitaryExpressionGenerator>(
+        expr: E,
+        control_radices: QuditRadices,
+        control_levels: Vec<Vec<usize>>,
+    ) -> Self {
+        ControlledGate::from_gate(
+            Gate::Expression(expr.gen_expr()),
+            control_radices,
+            control_levels,
+        )
+    }
+
+    /// Construct a ControlledGate that keeps the gate being controlled.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The gate to control.
+    ///
+    /// * `control_radixes` - The number of levels for each control qudit.
+    ///
+    /// * `control_levels` - The levels of the control qudits that activate the
     ///   gate. If more than one level is selected, the subspace spanned by the
     ///   levels acts as a control subspace. If all levels are selected for a
     ///   given qudit, the operation is equivalent to the original gate without
@@ -152,8 +200,8 @@ impl ControlledGate {
     /// # Examples
     ///
     /// // TODO: Come back to later
-    pub fn new<E: UnitaryExpressionGenerator>(
-        expr: E,
+    pub fn from_gate<G: Into<Gate>>(
+        gate: G,
         control_radices: QuditRadices, // TODO: Make a ToRadices generic
         control_levels: Vec<Vec<usize>>,
     ) -> Self {
@@ -188,7 +236,8 @@ impl ControlledGate {
             panic!("Expected control levels to be unique.");
         }
 
-        let gate_expr = expr.gen_expr();
+        let gate = gate.into();
+        let gate_expr = gate.gen_expr();
         let gate_dim = gate_expr.dimension();
 
         // Build appropriately sized identity expression
@@ -198,7 +247,7 @@ impl ControlledGate {
 
         // Embed gate expression into identity expression at correct spots
         let diagonal_indices: Vec<usize> =
-            ControlledGate::cartesian_product(control_levels)
+            ControlledGate::cartesian_product(control_levels.clone())
                 .into_iter()
                 .map(|block_idx_expansion| {
                     control_radices.compress(&block_idx_expansion)
@@ -210,7 +259,39 @@ impl ControlledGate {
             expr.embed(gate_expr.clone(), *diagonal_idx, *diagonal_idx);
         }
 
-        ControlledGate { expr }
+        ControlledGate {
+            gate: Box::new(gate),
+            control_radices,
+            control_levels,
+            expr,
+        }
+    }
+
+    /// The gate being controlled.
+    pub fn gate(&self) -> &Gate {
+        &self.gate
+    }
+
+    /// The number of levels for each control qudit.
+    pub fn control_radices(&self) -> &QuditRadices {
+        &self.control_radices
+    }
+
+    /// The levels of the control qudits that activate the gate.
+    pub fn control_levels(&self) -> &[Vec<usize>] {
+        &self.control_levels
+    }
+
+    /// Construct the inverse of this ControlledGate.
+    ///
+    /// The inverse of a controlled gate is the controlled inverse of the
+    /// gate, activated by the same control levels.
+    pub fn inverse(&self) -> Self {
+        ControlledGate::from_gate(
+            self.gate.inverse(),
+            self.control_radices.clone(),
+            self.control_levels.clone(),
+        )
     }
 
     /// Calculates the cartesian product of the control levels.
@@ -281,3 +362,88 @@ impl UnitaryExpressionGenerator for ControlledGate {
         self.expr.clone()
     }
 }
+
+impl QuditGate for ControlledGate {
+    fn name(&self) -> String {
+        format!("Controlled({})", self.gate.name())
+    }
+
+    #[inline]
+    fn param_names(&self) -> Vec<String> {
+        self.gate.param_names()
+    }
+
+    #[inline]
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        self.gate.param_specs()
+    }
+}
+
+#[cfg(feature = "proptest")]
+pub mod strategies {
+    use proptest::prelude::*;
+    use proptest::strategy::BoxedStrategy;
+    use qudit_core::radices;
+
+    use super::*;
+    use crate::strategies::{arbitrary_control_levels, ArbitraryGateWithRadices, RadixBounds};
+    use crate::utils::{radices_from_slice, radices_to_vec};
+
+    impl Arbitrary for ControlledGate {
+        type Parameters = (Option<Gate>, RadixBounds);
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+            let (gate, bounds) = args;
+            let gate_strat = match gate {
+                Some(gate) => Just(gate).boxed(),
+                None => any_with::<Gate>(bounds),
+            };
+
+            (gate_strat, bounds.radix())
+                .prop_flat_map(|(gate, radix)| {
+                    arbitrary_control_levels(radix).prop_map(move |levels| {
+                        ControlledGate::from_gate(gate.clone(), radices![radix], vec![levels])
+                    })
+                })
+                .boxed()
+        }
+    }
+
+    impl ArbitraryGateWithRadices for ControlledGate {
+        fn arbitrary_with_radices(
+            radices: QuditRadices,
+        ) -> Option<BoxedStrategy<Gate>> {
+            let radices = radices_to_vec(&radices);
+            let (target_radix, control_radices) = radices.split_last()?;
+            if control_radices.is_empty() {
+                return None;
+            }
+
+            let control_radices = control_radices.to_vec();
+            let levels_strat: Vec<BoxedStrategy<Vec<usize>>> = control_radices
+                .iter()
+                .map(|radix| arbitrary_control_levels(*radix))
+                .collect();
+            let gate_strat = Gate::arbitrary_with_radices_no_rec(radices![*target_radix])?;
+
+            Some(
+                (gate_strat, levels_strat)
+                    .prop_map(move |(gate, levels)| {
+                        Gate::from(ControlledGate::from_gate(
+                            gate,
+                            radices_from_slice(&control_radices),
+                            levels,
+                        ))
+                    })
+                    .boxed(),
+            )
+        }
+
+        fn arbitrary_with_radices_no_rec(
+            _radices: QuditRadices,
+        ) -> Option<BoxedStrategy<Gate>> {
+            None
+        }
+    }
+}
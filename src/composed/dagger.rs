@@ -1,21 +1,32 @@
 use qudit_core::{HasParams, QuditRadices, QuditSystem};
 use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
 
+use crate::Gate;
+use crate::ParamSpec;
+use crate::QuditGate;
+
 /// An arbitrary inverted gate.
 ///
 /// Given any gate, DaggerGate takes the conjugate transpose of the input gate.
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct DaggerGate {
-    // The expression being inverted.
+    // The gate being inverted.
+    gate: Box<Gate>,
+
+    // The expression of the inverted gate.
     expr: UnitaryExpression,
 }
 
 impl DaggerGate {
-    /// Construct a DaggerGate.
+    /// Construct a DaggerGate from any unitary expression generator.
+    ///
+    /// The generated expression is inverted as a `Gate::Expression`, so the
+    /// structure of `expr` is not kept. Use [`DaggerGate::from_gate`] to
+    /// invert a [`Gate`] and keep it.
     ///
     /// # Arguments
     ///
-    /// * `gate` - The gate to invert.
+    /// * `expr` - The gate to invert.
     ///
     /// # Returns
     ///
@@ -25,9 +36,27 @@ impl DaggerGate {
     ///
     /// // TODO: Come back to later
     pub fn new<E: UnitaryExpressionGenerator>(expr: E) -> Self {
-        let gate_expr = expr.gen_expr();
-        let expr = gate_expr.conjugate().transpose();
-        DaggerGate { expr }
+        DaggerGate::from_gate(Gate::Expression(expr.gen_expr()))
+    }
+
+    /// Construct a DaggerGate that keeps the gate being inverted.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The gate to invert.
+    ///
+    /// # Returns
+    ///
+    /// A new DaggerGate.
+    pub fn from_gate<G: Into<Gate>>(gate: G) -> Self {
+        let gate = gate.into();
+        let expr = gate.gen_expr().conjugate().transpose();
+        DaggerGate { gate: Box::new(gate), expr }
+    }
+
+    /// The gate being inverted.
+    pub fn gate(&self) -> &Gate {
+        &self.gate
     }
 }
 
@@ -62,75 +91,59 @@ impl UnitaryExpressionGenerator for DaggerGate {
     }
 }
 
-#[cfg(test)]
-pub mod strategies {
-    // use proptest::prelude::*;
-    // use proptest::strategy::BoxedStrategy;
-    // use proptest::strategy::Strategy;
-
-    // use super::*;
-    // use crate::gates::strategies::ArbitraryGateWithRadices;
-
-    // impl Arbitrary for DaggerGate {
-    //     type Parameters = Option<Gate>;
-    //     type Strategy = BoxedStrategy<Self>;
-
-    //     fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
-    //         let gate_strat = match args {
-    //             Some(gate) => Just(gate).boxed(),
-    //             None => any_with::<QuditRadices>((2, 4, 1, 1))
-    //                 .prop_flat_map(|radices| {
-    //                     Gate::arbitrary_with_radices_no_rec(radices).unwrap()
-    //                 })
-    //                 .boxed(),
-    //         };
-
-    //         gate_strat.prop_map(|gate| DaggerGate::new(gate)).boxed()
-    //     }
-    // }
-
-    // impl ArbitraryGateWithRadices for DaggerGate {
-    //     fn arbitrary_with_radices(
-    //         radices: QuditRadices,
-    //     ) -> Option<BoxedStrategy<Gate>> {
-    //         if radices.get_num_qudits() != 1 {
-    //             return None;
-    //         }
-    //         Some(
-    //             Gate::arbitrary_with_radices_no_rec(radices)
-    //                 .unwrap()
-    //                 .prop_map(|g| Gate::from(DaggerGate::new(g)))
-    //                 .boxed(),
-    //         )
-    //     }
-
-    //     fn arbitrary_with_radices_no_rec(
-    //         _radices: QuditRadices,
-    //     ) -> Option<BoxedStrategy<Gate>> {
-    //         None
-    //     }
-    // }
+impl QuditGate for DaggerGate {
+    fn name(&self) -> String {
+        format!("Dagger({})", self.gate.name())
+    }
+
+    #[inline]
+    fn param_names(&self) -> Vec<String> {
+        self.gate.param_names()
+    }
+
+    #[inline]
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        self.gate.param_specs()
+    }
 }
 
-// #[cfg(test)]
-// mod test {
-
-//     use super::*;
-
-//     #[test]
-//     fn test_qubit_h_gate() {
-//         let h_gate = HGate::new(2);
-//         let unitary = h_gate.get_unitary(&[]);
-//         let correct = array![
-//             [
-//                 c64::new(1.0 / 2.0_f64.sqrt(), 0.0),
-//                 c64::new(1.0 / 2.0_f64.sqrt(), 0.0)
-//             ],
-//             [
-//                 c64::new(1.0 / 2.0_f64.sqrt(), 0.0),
-//                 c64::new(-1.0 / 2.0_f64.sqrt(), 0.0)
-//             ]
-//         ];
-//         unitary.assert_close_to(&correct);
-//     }
-// }
+#[cfg(feature = "proptest")]
+pub mod strategies {
+    use proptest::prelude::*;
+    use proptest::strategy::BoxedStrategy;
+
+    use super::*;
+    use crate::strategies::{ArbitraryGateWithRadices, RadixBounds};
+
+    impl Arbitrary for DaggerGate {
+        type Parameters = Option<Gate>;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+            let gate_strat = match args {
+                Some(gate) => Just(gate).boxed(),
+                None => any_with::<Gate>(RadixBounds::default()),
+            };
+
+            gate_strat.prop_map(DaggerGate::from_gate).boxed()
+        }
+    }
+
+    impl ArbitraryGateWithRadices for DaggerGate {
+        fn arbitrary_with_radices(
+            radices: QuditRadices,
+        ) -> Option<BoxedStrategy<Gate>> {
+            Some(
+                Gate::arbitrary_with_radices_no_rec(radices)?
+                    .prop_map(|g| Gate::from(DaggerGate::from_gate(g)))
+                    .boxed(),
+            )
+        }
+
+        fn arbitrary_with_radices_no_rec(
+            _radices: QuditRadices,
+        ) -> Option<BoxedStrategy<Gate>> {
+            None
+        }
+    }
+}
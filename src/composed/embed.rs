@@ -0,0 +1,332 @@
+use qudit_core::{HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::constant::permutation::gen_permutation_matrix_expr;
+use crate::utils::{compress_levels, expand_index, radices_to_vec};
+use crate::Gate;
+use crate::ParamSpec;
+use crate::QuditGate;
+
+/// An arbitrary gate embedded into a subspace of larger qudits.
+///
+/// Given a gate $G$ acting on qudits with radices $d_k$, and for each qudit
+/// a map from its $d_k$ levels to distinct levels of a target qudit with
+/// $D_k \geq d_k$ levels, the embedded gate acts as $G$ on the selected
+/// levels and as the identity everywhere else.
+///
+/// For example, embedding a qubit X gate into levels $(0, 2)$ of a qutrit
+/// gives the following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     0 & 0 & 1 \\\\
+///     0 & 1 & 0 \\\\
+///     1 & 0 & 0 \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// If the selected levels form a contiguous block of the target space, in
+/// order, the gate is embedded directly into an identity expression.
+/// Otherwise, it is embedded at the start of the space and then moved onto
+/// the selected levels by conjugating with a permutation matrix. In both
+/// cases, the parameters of the embedded gate are those of $G$, in the
+/// same order.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct EmbeddedGate {
+    // The gate being embedded.
+    gate: Box<Gate>,
+
+    // The number of levels for each target qudit.
+    target_radices: QuditRadices,
+
+    // The target level of each level of each of the gate's qudits.
+    level_map: Vec<Vec<usize>>,
+
+    // The expression of the embedded gate.
+    expr: UnitaryExpression,
+}
+
+impl EmbeddedGate {
+    /// Construct an EmbeddedGate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The gate to embed.
+    ///
+    /// * `target_radices` - The number of levels for each target qudit.
+    ///
+    /// * `level_map` - For each of the gate's qudits, the target level that
+    ///   each of its levels is mapped to.
+    ///
+    /// # Returns
+    ///
+    /// A new EmbeddedGate.
+    ///
+    /// # Panics
+    ///
+    /// * If `target_radices` and `level_map` do not both have one entry per
+    ///   qudit of `gate`.
+    ///
+    /// * If any entry of `level_map` does not have one level per level of
+    ///   the corresponding qudit of `gate`.
+    ///
+    /// * If any level in `level_map` is greater than or equal to the
+    ///   corresponding radix in `target_radices`.
+    ///
+    /// * If any level in `level_map` is not unique.
+    ///
+    /// * If the gate changes the radices of its wires, e.g., a mixed-radix
+    ///   swap; see [`Gate::output_radices`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_core::{radices, HasParams, QuditSystem};
+    /// use qudit_gates::{EmbeddedGate, U3Gate};
+    ///
+    /// // A U3 gate driving the |0> <-> |2> transition of a qutrit.
+    /// let gate = EmbeddedGate::new(U3Gate, radices![3], vec![vec![0, 2]]);
+    /// assert_eq!(gate.radices(), radices![3]);
+    /// assert_eq!(gate.num_params(), 3);
+    /// ```
+    pub fn new<G: Into<Gate>>(
+        gate: G,
+        target_radices: QuditRadices,
+        level_map: Vec<Vec<usize>>,
+    ) -> Self {
+        let gate = gate.into();
+        if gate.output_radices() != gate.radices() {
+            panic!("Expected a gate whose output radices match its input radices.");
+        }
+
+        let gate_radices = radices_to_vec(&gate.radices());
+        let radices = radices_to_vec(&target_radices);
+
+        if radices.len() != gate_radices.len() || level_map.len() != gate_radices.len() {
+            panic!("Expected one target radix and level map per qudit of the gate.");
+        }
+
+        if level_map
+            .iter()
+            .zip(gate_radices.iter())
+            .any(|(levels, radix)| levels.len() != *radix)
+        {
+            panic!("Expected each level map to have one level per level of the gate's qudit.");
+        }
+
+        if level_map
+            .iter()
+            .zip(radices.iter())
+            .any(|(levels, radix)| levels.iter().any(|level| level >= radix))
+        {
+            panic!("Expected mapped levels to be less than the number of target levels.");
+        }
+
+        let mut level_sets = level_map.clone();
+        for levels in level_sets.iter_mut() {
+            levels.sort();
+            levels.dedup();
+        }
+        if level_sets
+            .iter()
+            .zip(level_map.iter())
+            .any(|(set, levels)| set.len() != levels.len())
+        {
+            panic!("Expected mapped levels to be unique.");
+        }
+
+        let gate_expr = gate.gen_expr();
+        let gate_dim = gate_expr.dimension();
+
+        // The target basis state of each of the gate's basis states.
+        let targets: Vec<usize> = (0..gate_dim)
+            .map(|index| {
+                let levels: Vec<usize> = expand_index(index, &gate_radices)
+                    .into_iter()
+                    .zip(level_map.iter())
+                    .map(|(level, map)| map[level])
+                    .collect();
+                compress_levels(&levels, &radices)
+            })
+            .collect();
+
+        let name = format!("Embedded({})", gate_expr.name());
+        let mut expr = UnitaryExpression::identity(&name, target_radices.clone());
+
+        let offset = targets[0];
+        if targets.iter().enumerate().all(|(k, target)| *target == offset + k) {
+            expr.embed(gate_expr, offset, offset);
+        } else {
+            expr.embed(gate_expr, 0, 0);
+
+            // Send position k to targets[k], and the positions after the
+            // gate's block to the remaining levels, in order.
+            let dim = expr.dimension();
+            let mut perm = targets.clone();
+            perm.extend((0..dim).filter(|index| !targets.contains(index)));
+            let mut inverse = vec![0; dim];
+            for (k, target) in perm.iter().enumerate() {
+                inverse[*target] = k;
+            }
+
+            let to_levels = gen_permutation_matrix_expr("EmbedPerm", &radices, &perm);
+            let from_levels = gen_permutation_matrix_expr("EmbedPermdg", &radices, &inverse);
+            expr = to_levels.dot(&expr).dot(&from_levels);
+        }
+
+        EmbeddedGate {
+            gate: Box::new(gate),
+            target_radices,
+            level_map,
+            expr,
+        }
+    }
+
+    /// The gate being embedded.
+    pub fn gate(&self) -> &Gate {
+        &self.gate
+    }
+
+    /// The number of levels for each target qudit.
+    pub fn target_radices(&self) -> &QuditRadices {
+        &self.target_radices
+    }
+
+    /// The target level of each level of each of the gate's qudits.
+    pub fn level_map(&self) -> &[Vec<usize>] {
+        &self.level_map
+    }
+
+    /// Construct the inverse of this EmbeddedGate.
+    ///
+    /// The inverse of an embedded gate is the embedded inverse of the gate,
+    /// on the same levels.
+    pub fn inverse(&self) -> Self {
+        EmbeddedGate::new(
+            self.gate.inverse(),
+            self.target_radices.clone(),
+            self.level_map.clone(),
+        )
+    }
+}
+
+impl HasParams for EmbeddedGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        self.expr.num_params()
+    }
+}
+
+impl QuditSystem for EmbeddedGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        self.target_radices.clone()
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        self.target_radices.len()
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.expr.dimension()
+    }
+}
+
+impl UnitaryExpressionGenerator for EmbeddedGate {
+    #[inline]
+    fn gen_expr(&self) -> UnitaryExpression {
+        self.expr.clone()
+    }
+}
+
+impl QuditGate for EmbeddedGate {
+    fn name(&self) -> String {
+        format!("Embedded({})", self.gate.name())
+    }
+
+    #[inline]
+    fn param_names(&self) -> Vec<String> {
+        self.gate.param_names()
+    }
+
+    #[inline]
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        self.gate.param_specs()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::assert_gradient_matches_finite_difference;
+    use crate::U3Gate;
+    use crate::XGate;
+    use qudit_core::c64;
+    use qudit_core::radices;
+    use qudit_core::unitary::UnitaryFn;
+    use qudit_core::unitary::UnitaryMatrix;
+
+    #[test]
+    fn test_x_on_outer_qutrit_levels() {
+        let gate = EmbeddedGate::new(XGate::new(2), radices![3], vec![vec![0, 2]]);
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(unitary, [
+            [0, 0, 1],
+            [0, 1, 0],
+            [1, 0, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_x_on_contiguous_qutrit_levels() {
+        let gate = EmbeddedGate::new(XGate::new(2), radices![3], vec![vec![1, 2]]);
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(unitary, [
+            [1, 0, 0],
+            [0, 0, 1],
+            [0, 1, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_embedded_rotations_match_subspace_rotations() {
+        for levels in [(0, 1), (1, 2), (0, 2), (2, 0)] {
+            let level_map = vec![vec![levels.0, levels.1]];
+            let cases = [
+                (Gate::RX(2, (0, 1)), Gate::RX(3, levels)),
+                (Gate::RY(2, (0, 1)), Gate::RY(3, levels)),
+                (Gate::RZ(2, (0, 1)), Gate::RZ(3, levels)),
+            ];
+            for (qubit_gate, qutrit_gate) in cases {
+                let embedded = Gate::from(EmbeddedGate::new(
+                    qubit_gate,
+                    radices![3],
+                    level_map.clone(),
+                ));
+                assert!(embedded.is_equivalent(&qutrit_gate, 1e-8), "{:?}", levels);
+            }
+        }
+    }
+
+    #[test]
+    fn test_embedded_gradient() {
+        let gate = EmbeddedGate::new(U3Gate, radices![4], vec![vec![3, 1]]);
+        let params = Gate::from(gate.clone()).random_params(&mut rand::thread_rng());
+        assert_gradient_matches_finite_difference(&gate, &params, 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_repeated_levels() {
+        EmbeddedGate::new(XGate::new(2), radices![3], vec![vec![1, 1]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radix_changing_gate() {
+        let swap = crate::SwapGate::new_mixed(2, 3);
+        EmbeddedGate::new(swap, radices![3, 3], vec![vec![0, 1], vec![0, 1, 2]]);
+    }
+}
@@ -0,0 +1,363 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use qudit_core::{HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::utils::{radices_to_vec, remap_params};
+use crate::Gate;
+use crate::ParamSpec;
+use crate::QuditGate;
+
+/// An arbitrary gate with some of its parameters bound to constants.
+///
+/// The constants are substituted into the body of the gate's expression,
+/// so the fixed gate only takes the remaining free parameters, in their
+/// original order. For example, fixing $\theta_0 = \pi/2$ of a U3 gate
+/// gives a gate taking the two parameters $(\theta_1, \theta_2)$.
+/// Gradients are taken with respect to the free parameters only.
+///
+/// Parameters can be fixed on the built-in parameterized gates, on their
+/// inverses, and on other fixed gates.
+#[derive(Clone, Debug)]
+pub struct FixedParamGate {
+    // The gate whose parameters are fixed.
+    gate: Box<Gate>,
+
+    // The fixed parameters, as (index, value) pairs sorted by index.
+    fixed: Vec<(usize, f64)>,
+
+    // The expression of the fixed gate.
+    expr: UnitaryExpression,
+}
+
+impl FixedParamGate {
+    /// Construct a FixedParamGate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The gate whose parameters are fixed.
+    ///
+    /// * `fixed` - A map from the index of each fixed parameter of `gate`
+    ///   to its value.
+    ///
+    /// # Returns
+    ///
+    /// A new FixedParamGate.
+    ///
+    /// # Panics
+    ///
+    /// * If any index in `fixed` is not less than the number of parameters
+    ///   of `gate`.
+    ///
+    /// * If any value in `fixed` is not finite.
+    ///
+    /// * If `fixed` is not empty and `gate` is not a built-in parameterized
+    ///   gate, the inverse of one, or a FixedParamGate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::f64::consts::PI;
+    ///
+    /// use qudit_core::HasParams;
+    /// use qudit_gates::{FixedParamGate, Gate, QuditGate};
+    ///
+    /// let gate = FixedParamGate::new(Gate::U3(), HashMap::from([(0, PI / 2.0)]));
+    /// assert_eq!(gate.num_params(), 2);
+    /// assert_eq!(gate.param_names(), vec!["θ1", "θ2"]);
+    /// ```
+    pub fn new<G: Into<Gate>>(gate: G, fixed: HashMap<usize, f64>) -> Self {
+        let gate = gate.into();
+
+        if fixed.keys().any(|index| *index >= gate.num_params()) {
+            panic!("Expected fixed parameter indices to be less than the number of parameters.");
+        }
+
+        if fixed.values().any(|value| !value.is_finite()) {
+            panic!("Expected finite fixed parameter values.");
+        }
+
+        let mut fixed: Vec<(usize, f64)> = fixed.into_iter().collect();
+        fixed.sort_by_key(|(index, _)| *index);
+
+        let expr = if fixed.is_empty() {
+            gate.gen_expr()
+        } else {
+            let body = gen_fixed_body(&gate, &fixed)
+                .expect("Expected a built-in parameterized gate to fix parameters of.");
+            let names = gate.param_names();
+            let free: Vec<&str> = (0..names.len())
+                .filter(|index| fixed.iter().all(|(fixed_index, _)| fixed_index != index))
+                .map(|index| names[index].as_str())
+                .collect();
+            let radices: Vec<String> =
+                radices_to_vec(&gate.radices()).iter().map(|r| r.to_string()).collect();
+            let proto = format!("utry Fixed<{}>({})", radices.join(", "), free.join(", "));
+            UnitaryExpression::new(proto + "{" + &body + "}")
+        };
+
+        FixedParamGate { gate: Box::new(gate), fixed, expr }
+    }
+
+    /// The gate whose parameters are fixed.
+    pub fn gate(&self) -> &Gate {
+        &self.gate
+    }
+
+    /// The fixed parameters, as (index, value) pairs sorted by index.
+    pub fn fixed_params(&self) -> &[(usize, f64)] {
+        &self.fixed
+    }
+
+    /// The indices of the free parameters of the original gate, in order.
+    pub fn free_param_indices(&self) -> Vec<usize> {
+        (0..self.gate.num_params())
+            .filter(|index| !self.is_fixed(*index))
+            .collect()
+    }
+
+    /// Expand the free parameters into a full parameter vector for the
+    /// original gate.
+    ///
+    /// # Panics
+    ///
+    /// * If `params` does not have one value per free parameter.
+    pub fn full_params(&self, params: &[f64]) -> Vec<f64> {
+        if params.len() != self.num_params() {
+            panic!("Expected one value per free parameter.");
+        }
+
+        let mut free = params.iter();
+        let mut fixed = self.fixed.iter().peekable();
+        (0..self.gate.num_params())
+            .map(|index| match fixed.peek() {
+                Some((fixed_index, value)) if *fixed_index == index => {
+                    fixed.next();
+                    *value
+                },
+                _ => *free.next().unwrap(),
+            })
+            .collect()
+    }
+
+    /// Construct the inverse of this FixedParamGate.
+    ///
+    /// The inverse of a gate takes the same parameters as the gate, so the
+    /// same parameters are fixed in the inverse of the original gate.
+    pub fn inverse(&self) -> Self {
+        FixedParamGate::new(self.gate.inverse(), self.fixed.iter().copied().collect())
+    }
+
+    #[inline]
+    fn is_fixed(&self, index: usize) -> bool {
+        self.fixed.iter().any(|(fixed_index, _)| *fixed_index == index)
+    }
+}
+
+/// The body of the expression of `gate` with the `fixed` parameters
+/// replaced by their values, or `None` if the body of `gate` is not known.
+fn gen_fixed_body(gate: &Gate, fixed: &[(usize, f64)]) -> Option<String> {
+    let body = gen_body(gate)?;
+    let names = gate.param_names();
+    Some(remap_params(&body, |ident| {
+        fixed
+            .iter()
+            .find(|(index, _)| names[*index] == ident)
+            .map(|(_, value)| real_literal(*value))
+    }))
+}
+
+/// The body of the expression of `gate`, in terms of its parameter names,
+/// or `None` if the body of `gate` is not known.
+fn gen_body(gate: &Gate) -> Option<String> {
+    match gate {
+        Gate::PGate(gate) => Some(gate.gen_body()),
+        Gate::U3Gate(gate) => Some(gate.gen_body()),
+        Gate::RXGate(gate) => Some(gate.gen_body()),
+        Gate::RYGate(gate) => Some(gate.gen_body()),
+        Gate::RZGate(gate) => Some(gate.gen_body()),
+        Gate::RXXGate(gate) => Some(gate.gen_body()),
+        Gate::RYYGate(gate) => Some(gate.gen_body()),
+        Gate::RZZGate(gate) => Some(gate.gen_body()),
+        Gate::RZXGate(gate) => Some(gate.gen_body()),
+        Gate::Inverse(inverse) => match inverse.gate() {
+            Gate::PGate(gate) => Some(gate.gen_inverse_body()),
+            Gate::U3Gate(gate) => Some(gate.gen_inverse_body()),
+            Gate::RXGate(gate) => Some(gate.gen_inverse_body()),
+            Gate::RYGate(gate) => Some(gate.gen_inverse_body()),
+            Gate::RZGate(gate) => Some(gate.gen_inverse_body()),
+            Gate::RXXGate(gate) => Some(gate.gen_inverse_body()),
+            Gate::RYYGate(gate) => Some(gate.gen_inverse_body()),
+            Gate::RZZGate(gate) => Some(gate.gen_inverse_body()),
+            Gate::RZXGate(gate) => Some(gate.gen_inverse_body()),
+            _ => None,
+        },
+        Gate::Fixed(gate) => gen_fixed_body(&gate.gate, &gate.fixed),
+        _ => None,
+    }
+}
+
+/// Format a real number for an expression, with `~` as unary minus.
+fn real_literal(x: f64) -> String {
+    if x < 0.0 {
+        format!("(~{})", -x)
+    } else {
+        format!("{}", x)
+    }
+}
+
+// The fixed values are compared by their bit patterns, so that
+// FixedParamGate can be used as a key alongside the other gates.
+impl PartialEq for FixedParamGate {
+    fn eq(&self, other: &Self) -> bool {
+        self.gate == other.gate
+            && self.fixed.len() == other.fixed.len()
+            && self.fixed.iter().zip(other.fixed.iter()).all(|(a, b)| {
+                a.0 == b.0 && a.1.to_bits() == b.1.to_bits()
+            })
+    }
+}
+
+impl Eq for FixedParamGate {}
+
+impl Hash for FixedParamGate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.gate.hash(state);
+        for (index, value) in self.fixed.iter() {
+            index.hash(state);
+            value.to_bits().hash(state);
+        }
+    }
+}
+
+impl HasParams for FixedParamGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        self.gate.num_params() - self.fixed.len()
+    }
+}
+
+impl QuditSystem for FixedParamGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        self.gate.radices()
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        self.gate.num_qudits()
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.gate.dimension()
+    }
+}
+
+impl UnitaryExpressionGenerator for FixedParamGate {
+    #[inline]
+    fn gen_expr(&self) -> UnitaryExpression {
+        self.expr.clone()
+    }
+}
+
+impl QuditGate for FixedParamGate {
+    fn name(&self) -> String {
+        format!("Fixed({})", self.gate.name())
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        let names = self.gate.param_names();
+        self.free_param_indices().into_iter().map(|index| names[index].clone()).collect()
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        let specs = self.gate.param_specs();
+        self.free_param_indices().into_iter().map(|index| specs[index].clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::f64::consts::PI;
+
+    use crate::testing::{assert_gradient_matches_finite_difference, assert_unitaries_close};
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    fn assert_matches_original(gate: &FixedParamGate) {
+        let params = Gate::from(gate.clone()).random_params(&mut rand::thread_rng());
+        let full_params = gate.full_params(&params);
+        let a: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&params);
+        let b: UnitaryMatrix<c64> = gate.gate().gen_expr().get_unitary(&full_params);
+        assert_unitaries_close(&a, &b, 1e-10);
+    }
+
+    #[test]
+    fn test_fixed_u3() {
+        let gate = FixedParamGate::new(Gate::U3(), HashMap::from([(0, PI / 2.0)]));
+        assert_eq!(gate.num_params(), 2);
+        assert_eq!(gate.gen_expr().num_params(), 2);
+        assert_eq!(gate.full_params(&[0.3, 0.7]), vec![PI / 2.0, 0.3, 0.7]);
+        assert_matches_original(&gate);
+    }
+
+    #[test]
+    fn test_fixed_p() {
+        let gate = FixedParamGate::new(Gate::P(4), HashMap::from([(0, 0.1), (2, 1.2)]));
+        assert_eq!(gate.num_params(), 1);
+        assert_eq!(gate.free_param_indices(), vec![1]);
+        assert_eq!(gate.param_names(), vec!["θ1"]);
+        assert_matches_original(&gate);
+    }
+
+    #[test]
+    fn test_all_params_fixed() {
+        // P(π) on a qubit is the Z gate.
+        let gate = FixedParamGate::new(Gate::P(2), HashMap::from([(0, PI)]));
+        assert_eq!(gate.num_params(), 0);
+        assert!(Gate::from(gate).is_equivalent(&Gate::Z(2), 1e-8));
+    }
+
+    #[test]
+    fn test_fixed_rotations() {
+        let gates = [Gate::RX(3, (0, 2)), Gate::RZZ(3), Gate::RXX(), Gate::P(3).inverse()];
+        for gate in gates {
+            let fixed = FixedParamGate::new(gate.clone(), HashMap::from([(0, -0.8)]));
+            assert_eq!(fixed.num_params(), gate.num_params() - 1);
+            assert_matches_original(&fixed);
+        }
+    }
+
+    #[test]
+    fn test_fixed_twice() {
+        let once = FixedParamGate::new(Gate::U3(), HashMap::from([(1, 0.4)]));
+        let twice = FixedParamGate::new(once, HashMap::from([(1, -1.3)]));
+        assert_eq!(twice.param_names(), vec!["θ0"]);
+        let a: UnitaryMatrix<c64> = twice.gen_expr().get_unitary(&[0.9]);
+        let b: UnitaryMatrix<c64> = Gate::U3().gen_expr().get_unitary(&[0.9, 0.4, -1.3]);
+        assert_unitaries_close(&a, &b, 1e-10);
+    }
+
+    #[test]
+    fn test_fixed_gradient() {
+        let gate = FixedParamGate::new(Gate::U3(), HashMap::from([(1, 0.4)]));
+        let params = Gate::from(gate.clone()).random_params(&mut rand::thread_rng());
+        assert_gradient_matches_finite_difference(&gate, &params, 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_range_index() {
+        FixedParamGate::new(Gate::P(2), HashMap::from([(1, 0.0)]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unknown_body() {
+        FixedParamGate::new(Gate::CP(), HashMap::from([(0, 0.0)]));
+    }
+}
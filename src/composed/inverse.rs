@@ -0,0 +1,182 @@
+use qudit_core::{HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::Gate;
+use crate::ParamSpec;
+use crate::QuditGate;
+
+/// The closed-form inverse of a built-in gate.
+///
+/// Where [`crate::DaggerGate`] takes the conjugate transpose of an arbitrary
+/// expression, InverseGate keeps the gate it inverts and generates that
+/// gate's inverse in closed form. For example, the inverse of $P(\theta)$
+/// evaluates $P(-\theta)$, and the inverse of $U3(\theta_0, \theta_1,
+/// \theta_2)$ evaluates $U3(-\theta_0, -\theta_2, -\theta_1)$. The inverse
+/// takes the same parameters as the original gate, and inverting it again
+/// with [`Gate::inverse`] gives back the original gate.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct InverseGate {
+    // The gate being inverted.
+    gate: Box<Gate>,
+
+    // The expression of the inverted gate.
+    expr: UnitaryExpression,
+}
+
+impl InverseGate {
+    /// Construct an InverseGate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The gate to invert. See [`InverseGate::has_closed_form`]
+    ///   for the supported gates.
+    ///
+    /// # Returns
+    ///
+    /// A new InverseGate.
+    ///
+    /// # Panics
+    ///
+    /// * If `gate` has no closed-form inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_gates::{Gate, InverseGate};
+    ///
+    /// let inverse = InverseGate::new(Gate::P(3));
+    /// assert_eq!(inverse.gate(), &Gate::P(3));
+    /// assert_eq!(Gate::from(inverse).inverse(), Gate::P(3));
+    /// ```
+    pub fn new<G: Into<Gate>>(gate: G) -> Self {
+        let gate = gate.into();
+        let expr = match &gate {
+            Gate::HGate(gate) => gate.gen_inverse_expr(),
+            Gate::PGate(gate) => gate.gen_inverse_expr(),
+            Gate::U3Gate(gate) => gate.gen_inverse_expr(),
+            Gate::WeylGate(gate) => gate.gen_inverse_expr(),
+            Gate::RXGate(gate) => gate.gen_inverse_expr(),
+            Gate::RYGate(gate) => gate.gen_inverse_expr(),
+            Gate::RZGate(gate) => gate.gen_inverse_expr(),
+            Gate::RXXGate(gate) => gate.gen_inverse_expr(),
+            Gate::RYYGate(gate) => gate.gen_inverse_expr(),
+            Gate::RZZGate(gate) => gate.gen_inverse_expr(),
+            Gate::RZXGate(gate) => gate.gen_inverse_expr(),
+            _ => panic!("Expected a gate with a closed-form inverse."),
+        };
+        InverseGate { gate: Box::new(gate), expr }
+    }
+
+    /// Whether `gate` has a closed-form inverse that an InverseGate can
+    /// generate.
+    ///
+    /// These are the qudit Hadamard, phase, U3, Weyl, rotation and Ising
+    /// gates.
+    pub fn has_closed_form(gate: &Gate) -> bool {
+        matches!(
+            gate,
+            Gate::HGate(_)
+                | Gate::PGate(_)
+                | Gate::U3Gate(_)
+                | Gate::WeylGate(_)
+                | Gate::RXGate(_)
+                | Gate::RYGate(_)
+                | Gate::RZGate(_)
+                | Gate::RXXGate(_)
+                | Gate::RYYGate(_)
+                | Gate::RZZGate(_)
+                | Gate::RZXGate(_)
+        )
+    }
+
+    /// The gate being inverted.
+    pub fn gate(&self) -> &Gate {
+        &self.gate
+    }
+}
+
+impl HasParams for InverseGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        self.expr.num_params()
+    }
+}
+
+impl QuditSystem for InverseGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        self.expr.radices()
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        self.expr.num_qudits()
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.expr.dimension()
+    }
+}
+
+impl UnitaryExpressionGenerator for InverseGate {
+    #[inline]
+    fn gen_expr(&self) -> UnitaryExpression {
+        self.expr.clone()
+    }
+}
+
+impl QuditGate for InverseGate {
+    fn name(&self) -> String {
+        format!("Inverse({})", self.gate.name())
+    }
+
+    #[inline]
+    fn param_names(&self) -> Vec<String> {
+        self.gate.param_names()
+    }
+
+    #[inline]
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        self.gate.param_specs()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DaggerGate;
+
+    #[test]
+    fn test_inverse_round_trips() {
+        let gates = vec![
+            Gate::H(3),
+            Gate::P(3),
+            Gate::U3(),
+            Gate::RX(3, (0, 2)),
+            Gate::RZZ(3),
+            Gate::Weyl(3, 1, 1),
+        ];
+        for gate in gates {
+            let inverse = gate.inverse();
+            assert!(matches!(inverse, Gate::Inverse(_)), "{:?}", gate);
+            assert_eq!(inverse.inverse(), gate);
+        }
+    }
+
+    #[test]
+    fn test_inverse_matches_dagger() {
+        for gate in [Gate::P(4), Gate::U3(), Gate::RYY(), Gate::H(5)] {
+            let inverse = Gate::from(InverseGate::new(gate.clone()));
+            let dagger = Gate::from(DaggerGate::from_gate(gate.clone()));
+            assert_eq!(inverse.num_params(), gate.num_params());
+            assert!(inverse.is_equivalent(&dagger, 1e-8), "{:?}", gate);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_no_closed_form() {
+        InverseGate::new(Gate::CSUM(3));
+    }
+}
@@ -0,0 +1,223 @@
+use qudit_core::{HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::Gate;
+use crate::ParamSpec;
+use crate::PermutationGate;
+use crate::QuditGate;
+
+/// An arbitrary gate applied with its qudits reordered.
+///
+/// Given a gate $G$ and a permutation $\pi$ of its qudits, the permuted
+/// gate applies $G$ with its qudit $i$ on wire $\pi(i)$:
+///
+/// $$
+/// G_\pi = P_\pi G P_\pi^\dagger
+/// $$
+///
+/// where $P_\pi$ is the [`PermutationGate`] moving wire $i$ to wire
+/// $\pi(i)$. The radices of the permuted gate are the radices of $G$,
+/// reordered by $\pi$. The parameters are those of $G$, in the same order.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PermutedGate {
+    // The gate being permuted.
+    gate: Box<Gate>,
+
+    // The wire of each of the gate's qudits.
+    perm: Vec<usize>,
+
+    // The number of levels of each wire.
+    radices: QuditRadices,
+
+    // The expression of the permuted gate.
+    expr: UnitaryExpression,
+}
+
+impl PermutedGate {
+    /// Construct a PermutedGate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The gate to permute.
+    ///
+    /// * `perm` - The wire of each of the gate's qudits.
+    ///
+    /// # Returns
+    ///
+    /// A new PermutedGate.
+    ///
+    /// # Panics
+    ///
+    /// * If `perm` is not a permutation of the gate's qudit indices.
+    ///
+    /// * If the gate changes the radices of its wires, e.g., a mixed-radix
+    ///   swap; see [`Gate::output_radices`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_core::{radices, QuditSystem};
+    /// use qudit_gates::{ControlledGate, Gate, PermutedGate, XGate};
+    ///
+    /// // A CNOT controlled by the second qubit.
+    /// let cx = ControlledGate::from_gate(XGate::new(2), radices![2], vec![vec![1]]);
+    /// let xc = PermutedGate::new(cx, vec![1, 0]);
+    ///
+    /// // A controlled qutrit X, with the control qubit on the last wire.
+    /// let cx3 = ControlledGate::from_gate(XGate::new(3), radices![2], vec![vec![1]]);
+    /// let x3c = PermutedGate::new(cx3, vec![1, 0]);
+    /// assert_eq!(x3c.radices(), radices![3, 2]);
+    /// ```
+    pub fn new<G: Into<Gate>>(gate: G, perm: Vec<usize>) -> Self {
+        let gate = gate.into();
+        if gate.output_radices() != gate.radices() {
+            panic!("Expected a gate whose output radices match its input radices.");
+        }
+
+        let permutation = PermutationGate::new(gate.radices(), perm.clone());
+        let radices = permutation.output_radices();
+
+        let gate_expr = gate.gen_expr();
+        let expr = permutation
+            .gen_expr()
+            .dot(&gate_expr)
+            .dot(&permutation.inverse().gen_expr());
+
+        PermutedGate { gate: Box::new(gate), perm, radices, expr }
+    }
+
+    /// The gate being permuted.
+    pub fn gate(&self) -> &Gate {
+        &self.gate
+    }
+
+    /// The wire of each of the gate's qudits.
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Construct the inverse of this PermutedGate.
+    ///
+    /// The inverse of a permuted gate is the permuted inverse of the gate.
+    pub fn inverse(&self) -> Self {
+        PermutedGate::new(self.gate.inverse(), self.perm.clone())
+    }
+}
+
+impl HasParams for PermutedGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        self.expr.num_params()
+    }
+}
+
+impl QuditSystem for PermutedGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        self.radices.clone()
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        self.perm.len()
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.expr.dimension()
+    }
+}
+
+impl UnitaryExpressionGenerator for PermutedGate {
+    #[inline]
+    fn gen_expr(&self) -> UnitaryExpression {
+        self.expr.clone()
+    }
+}
+
+impl QuditGate for PermutedGate {
+    fn name(&self) -> String {
+        format!("Permuted({})", self.gate.name())
+    }
+
+    #[inline]
+    fn param_names(&self) -> Vec<String> {
+        self.gate.param_names()
+    }
+
+    #[inline]
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        self.gate.param_specs()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ControlledGate;
+    use crate::XGate;
+    use qudit_core::c64;
+    use qudit_core::radices;
+    use qudit_core::unitary::UnitaryFn;
+    use qudit_core::unitary::UnitaryMatrix;
+
+    #[test]
+    fn test_control_on_last_wire() {
+        let cx = ControlledGate::from_gate(XGate::new(2), radices![2], vec![vec![1]]);
+        let gate = PermutedGate::new(cx, vec![1, 0]);
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(unitary, [
+            [1, 0, 0, 0],
+            [0, 0, 0, 1],
+            [0, 0, 1, 0],
+            [0, 1, 0, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_identity_permutation() {
+        let gate = Gate::CSUM(3);
+        let permuted = Gate::from(PermutedGate::new(gate.clone(), vec![0, 1]));
+        assert!(permuted.is_equivalent(&gate, 1e-8));
+    }
+
+    #[test]
+    fn test_mixed_radices() {
+        let cx = ControlledGate::from_gate(XGate::new(3), radices![2], vec![vec![1]]);
+        let gate = PermutedGate::new(cx, vec![1, 0]);
+        assert_eq!(gate.radices(), radices![3, 2]);
+        assert_eq!(gate.gen_expr().radices(), radices![3, 2]);
+    }
+
+    #[test]
+    fn test_mixed_radix_cycle() {
+        // A CSUM from a qubit to a qutrit, with an idle qubit, moved so that
+        // the control is on wire 2 and the target on wire 0.
+        let csum = crate::CSumGate::new_mixed(2, 3);
+        let gate = crate::TensorGate::new(vec![Gate::from(csum), Gate::I(2)]);
+        let gate = PermutedGate::new(gate, vec![2, 0, 1]);
+        assert_eq!(gate.radices(), radices![3, 2, 2]);
+
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(unitary, [
+            [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0],
+            [0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            [0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0],
+            [0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
+            [0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0],
+            [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0],
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radix_changing_gate() {
+        PermutedGate::new(crate::SwapGate::new_mixed(2, 3), vec![1, 0]);
+    }
+}
@@ -0,0 +1,531 @@
+use std::f64::consts::PI;
+use std::hash::{Hash, Hasher};
+
+use qudit_core::c64;
+use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+use qudit_core::{HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::constant::permutation::gen_permutation_matrix_expr;
+use crate::utils::radices_to_vec;
+use crate::CSumGate;
+use crate::Gate;
+use crate::ParamSpec;
+use crate::QuditGate;
+use crate::WeylGate;
+
+/// The largest magnitude of a power, so that every integer power is exact
+/// as an `f64` and fits in an `i64`.
+const MAX_POWER: f64 = 9007199254740992.0;
+
+/// The largest integer power built by repeated squaring. The expression
+/// holds one copy of the gate per factor, so it grows with the power.
+const MAX_REPEATED_POWER: u64 = 1 << 10;
+
+/// A gate raised to a power.
+///
+/// Integer powers $G^k$ are supported for every gate. Where a closed form
+/// is known, it is used directly, so that, for example, $X^k$ remains a
+/// permutation and $H^4$ is the identity. Hermitian gates are reduced
+/// exactly for any $k$, e.g. $CNOT^k$ is $CNOT^{k \bmod 2}$. Otherwise, the
+/// expression of $G$, or of $G^{-1}$ if $k < 0$, is multiplied with itself
+/// by repeated squaring.
+///
+/// The power of a parameterized gate is a product of copies of the gate,
+/// each taking the gate's parameters in turn. Passing the same values to
+/// every copy evaluates the power at those values, e.g. $P(\theta)^3 =
+/// P(3\theta)$.
+///
+/// Real powers are supported for constant gates with a known spectrum, by
+/// scaling the gate's eigen-phases,
+/// $G^k = \sum_j e^{ik\phi_j} \ket{v_j}\bra{v_j}$.
+/// The principal branch is used, with every $\phi_j \in (-\pi, \pi]$.
+/// This covers:
+///
+/// * Diagonal gates, such as $Z$, $S$, $T$ and $CP$ at fixed phases.
+///
+/// * The shift gate $X$, which is diagonalized by the Fourier transform.
+///
+/// * Self-inverse gates, $G^2 \propto I$, such as $H$ on a qubit, $SWAP$
+///   and $CNOT$, whose eigenvalues are $\pm 1$ up to a global phase.
+#[derive(Clone, Debug)]
+pub struct PowerGate {
+    // The gate being raised to a power.
+    gate: Box<Gate>,
+
+    // The power.
+    power: f64,
+
+    // The expression of the power gate.
+    expr: UnitaryExpression,
+}
+
+impl PowerGate {
+    /// Construct a PowerGate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gate` - The gate to raise to a power.
+    ///
+    /// * `k` - The power. If `k` is not an integer, the gate must be
+    ///   constant and have a known spectrum; see [`PowerGate`].
+    ///
+    /// # Returns
+    ///
+    /// A new PowerGate.
+    ///
+    /// # Panics
+    ///
+    /// * If the gate changes the radices of its wires, e.g., a mixed-radix
+    ///   swap; see [`Gate::output_radices`].
+    ///
+    /// * If `k` is not finite, or its magnitude is greater than $2^{53}$.
+    ///
+    /// * If `k` is an integer greater than $2^{10}$ in magnitude and the
+    ///   gate has no closed-form power; see [`PowerGate`].
+    ///
+    /// * If `k` is not an integer and `gate` is parameterized or its
+    ///   spectrum is not known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_gates::{Gate, PowerGate};
+    ///
+    /// // X^2 on a qutrit is still a permutation.
+    /// let x2 = Gate::from(PowerGate::new(Gate::X(3), 2.0));
+    /// assert!(x2.is_permutation());
+    ///
+    /// // The square root of Z on a qubit is S.
+    /// let sqrt_z = Gate::from(PowerGate::new(Gate::Z(2), 0.5));
+    /// assert!(sqrt_z.is_equivalent(&Gate::S(), 1e-8));
+    /// ```
+    pub fn new<G: Into<Gate>>(gate: G, k: f64) -> Self {
+        let gate = gate.into();
+
+        if gate.output_radices() != gate.radices() {
+            panic!("Expected a gate whose output radices match its input radices.");
+        }
+
+        if !k.is_finite() || k.abs() > MAX_POWER {
+            panic!("Expected a finite power with magnitude at most 2^53.");
+        }
+
+        let expr = if k.fract() == 0.0 {
+            gen_integer_power_expr(&gate, k as i64)
+        } else {
+            gen_real_power_expr(&gate, k)
+        };
+
+        PowerGate { gate: Box::new(gate), power: k, expr }
+    }
+
+    /// The gate being raised to a power.
+    pub fn gate(&self) -> &Gate {
+        &self.gate
+    }
+
+    /// The power.
+    pub fn power(&self) -> f64 {
+        self.power
+    }
+
+    /// Construct the inverse of this PowerGate.
+    ///
+    /// The inverse of $G^k$ is $G^{-k}$.
+    pub fn inverse(&self) -> Self {
+        PowerGate::new((*self.gate).clone(), -self.power)
+    }
+}
+
+/// Generate the expression for an integer power of `gate`.
+fn gen_integer_power_expr(gate: &Gate, k: i64) -> UnitaryExpression {
+    let name = format!("Pow({})", gate.name());
+    let identity = || UnitaryExpression::identity(&name, gate.radices());
+    let reduce = |radix: usize| k.rem_euclid(radix as i64) as usize;
+
+    match gate {
+        Gate::IGate(_) => identity(),
+        Gate::XGate(x) => WeylGate::unphased(x.radix, reduce(x.radix), 0).gen_expr(),
+        Gate::ZGate(z) => WeylGate::unphased(z.radix, 0, reduce(z.radix)).gen_expr(),
+        Gate::WeylGate(w) if !w.phased && (w.a == 0 || w.b == 0) => {
+            WeylGate::unphased(w.radix, w.a * reduce(w.radix), w.b * reduce(w.radix))
+                .gen_expr()
+        },
+        Gate::CSumGate(csum) => CSumGate::new_general(
+            csum.control_radix,
+            csum.target_radix,
+            csum.multiplier * reduce(csum.target_radix),
+        )
+        .gen_expr(),
+        // H^2 maps |j> to |-j mod d>, so H^4 = I.
+        Gate::HGate(h) => match k.rem_euclid(4) {
+            0 => identity(),
+            1 => h.gen_expr(),
+            2 => {
+                let targets: Vec<usize> = (0..h.radix).map(|j| (h.radix - j) % h.radix).collect();
+                gen_permutation_matrix_expr("Parity", &[h.radix], &targets)
+            },
+            _ => gate.inverse().gen_expr(),
+        },
+        _ if k == 0 => identity(),
+        _ if k == 1 => gate.gen_expr(),
+        _ if k == -1 => gate.inverse().gen_expr(),
+        // A Hermitian gate squares to the identity, so G^k = G^(k mod 2).
+        _ if gate.is_hermitian() => match k.rem_euclid(2) {
+            0 => identity(),
+            _ => gate.gen_expr(),
+        },
+        _ if k > 0 => repeated_product(gate.gen_expr(), k.unsigned_abs()),
+        // G^-k = (G^-1)^k.
+        _ => repeated_product(gate.inverse().gen_expr(), k.unsigned_abs()),
+    }
+}
+
+/// The product of `n` copies of `expr`, for `n` at least one, built by
+/// repeated squaring.
+fn repeated_product(expr: UnitaryExpression, mut n: u64) -> UnitaryExpression {
+    if n > MAX_REPEATED_POWER {
+        panic!("Expected an integer power of at most {} for this gate.", MAX_REPEATED_POWER);
+    }
+
+    let mut square = expr;
+    let mut product: Option<UnitaryExpression> = None;
+    loop {
+        if n % 2 == 1 {
+            product = Some(match product {
+                Some(acc) => acc.dot(&square),
+                None => square.clone(),
+            });
+        }
+        n /= 2;
+        if n == 0 {
+            return product.unwrap();
+        }
+        square = square.dot(&square);
+    }
+}
+
+/// The scalar $c$ with $U^2 = cI$, for a self-inverse `utry`.
+fn involution_scale(utry: &UnitaryMatrix<c64>) -> c64 {
+    let mut c = c64::new(0.0, 0.0);
+    for m in 0..utry.dimension() {
+        c += utry[(0, m)] * utry[(m, 0)];
+    }
+    c
+}
+
+/// Generate the expression for a real power of `gate` by scaling its
+/// eigen-phases.
+fn gen_real_power_expr(gate: &Gate, k: f64) -> UnitaryExpression {
+    if gate.num_params() > 0 {
+        panic!("Expected a constant gate for a non-integer power.");
+    }
+
+    let dim = gate.dimension();
+    let utry: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+    let props = gate.properties();
+
+    let mut entries = vec![c64::new(0.0, 0.0); dim * dim];
+    if props.diagonal {
+        for j in 0..dim {
+            entries[j * dim + j] = cis(k * utry[(j, j)].arg());
+        }
+    } else if let Gate::XGate(x) = gate {
+        // X = Σ_m ω^m |f_m><f_m| with |f_m> = Σ_j ω^(-mj) |j> / √d.
+        let d = x.radix;
+        for i in 0..d {
+            for j in 0..d {
+                let mut entry = c64::new(0.0, 0.0);
+                for m in 0..d {
+                    let phase = principal_angle(2.0 * PI * m as f64 / d as f64);
+                    let overlap = 2.0 * PI * (m * (j + d - i)) as f64 / d as f64;
+                    entry += cis(k * phase + overlap);
+                }
+                entries[i * d + j] = entry / d as f64;
+            }
+        }
+    } else if props.self_inverse {
+        // U^2 = cI, so V = U/√c squares to the identity, and U has the
+        // eigenvalues ±√c on the projectors (I ± V)/2. Each eigen-phase is
+        // mapped into the principal branch before it is scaled by k.
+        let c = involution_scale(&utry);
+        let sqrt_c = cis(c.arg() / 2.0);
+        let plus_k = cis(k * principal_angle(c.arg() / 2.0));
+        let minus_k = cis(k * principal_angle(c.arg() / 2.0 + PI));
+        for i in 0..dim {
+            for j in 0..dim {
+                let v = utry[(i, j)] / sqrt_c;
+                let delta = c64::new(if i == j { 1.0 } else { 0.0 }, 0.0);
+                entries[i * dim + j] = plus_k * (delta + v) / 2.0 + minus_k * (delta - v) / 2.0;
+            }
+        }
+    } else {
+        panic!("Expected a diagonal, shift, or self-inverse gate for a non-integer power.");
+    }
+
+    gen_literal_expr("Pow", &radices_to_vec(&gate.radices()), &entries)
+}
+
+/// Build the expression for a constant matrix with row-major `entries`.
+fn gen_literal_expr(name: &str, radices: &[usize], entries: &[c64]) -> UnitaryExpression {
+    let dim: usize = radices.iter().product();
+    let radix_list: Vec<String> = radices.iter().map(|r| r.to_string()).collect();
+    let proto = format!("utry {}<{}>()", name, radix_list.join(", "));
+
+    let mut body = "[".to_string();
+    for i in 0..dim {
+        body += "[";
+        for j in 0..dim {
+            let entry = entries[i * dim + j];
+            body += &format!("{} + {}*i, ", real_literal(entry.re), real_literal(entry.im));
+        }
+        body += "],";
+    }
+    body += "]";
+
+    UnitaryExpression::new(proto + "{" + &body + "}")
+}
+
+/// Format a real number for an expression, with `~` as unary minus.
+fn real_literal(x: f64) -> String {
+    if x.abs() < 1e-15 {
+        "0".to_string()
+    } else if x < 0.0 {
+        format!("~{}", -x)
+    } else {
+        format!("{}", x)
+    }
+}
+
+/// Map an angle into the principal branch $(-\pi, \pi]$.
+#[inline]
+fn principal_angle(theta: f64) -> f64 {
+    let wrapped = theta.rem_euclid(2.0 * PI);
+    if wrapped > PI {
+        wrapped - 2.0 * PI
+    } else {
+        wrapped
+    }
+}
+
+#[inline]
+fn cis(theta: f64) -> c64 {
+    c64::new(theta.cos(), theta.sin())
+}
+
+// The power is compared by its bit pattern, so that PowerGate can be used
+// as a key alongside the other gates.
+impl PartialEq for PowerGate {
+    fn eq(&self, other: &Self) -> bool {
+        self.gate == other.gate && self.power.to_bits() == other.power.to_bits()
+    }
+}
+
+impl Eq for PowerGate {}
+
+impl Hash for PowerGate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.gate.hash(state);
+        self.power.to_bits().hash(state);
+    }
+}
+
+impl HasParams for PowerGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        self.expr.num_params()
+    }
+}
+
+impl QuditSystem for PowerGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        self.gate.radices()
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        self.gate.num_qudits()
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.gate.dimension()
+    }
+}
+
+impl UnitaryExpressionGenerator for PowerGate {
+    #[inline]
+    fn gen_expr(&self) -> UnitaryExpression {
+        self.expr.clone()
+    }
+}
+
+impl QuditGate for PowerGate {
+    fn name(&self) -> String {
+        format!("Power({}, {})", self.gate.name(), self.power)
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        self.gate.param_names().into_iter().cycle().take(self.num_params()).collect()
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        self.gate.param_specs().into_iter().cycle().take(self.num_params()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::assert_unitaries_close;
+    use crate::ProductGate;
+    use crate::TensorGate;
+    use qudit_core::radices;
+
+    fn power(gate: Gate, k: f64) -> Gate {
+        Gate::from(PowerGate::new(gate, k))
+    }
+
+    /// The repeated product of `gate` with itself `k` times.
+    fn repeated(gate: Gate, k: usize) -> Gate {
+        let location: Vec<usize> = (0..gate.num_qudits()).collect();
+        Gate::from(ProductGate::new(gate.radices(), vec![(gate, location); k]))
+    }
+
+    /// Assert that two constant gates have the same unitary.
+    fn assert_same_unitary(a: &Gate, b: &Gate) {
+        let a: UnitaryMatrix<c64> = a.gen_expr().get_unitary(&[]);
+        let b: UnitaryMatrix<c64> = b.gen_expr().get_unitary(&[]);
+        assert_unitaries_close(&a, &b, 1e-8);
+    }
+
+    #[test]
+    fn test_integer_powers_match_repeated_products() {
+        for radix in 2..=5 {
+            for k in 1..=5 {
+                let gates = [Gate::X(radix), Gate::Z(radix), Gate::H(radix), Gate::CSUM(radix)];
+                for gate in gates {
+                    let expected = repeated(gate.clone(), k);
+                    assert_same_unitary(&power(gate.clone(), k as f64), &expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_negative_and_zero_powers() {
+        for radix in 2..=4 {
+            let x = Gate::X(radix);
+            assert_same_unitary(&power(x.clone(), -1.0), &x.inverse());
+            assert_same_unitary(&power(x.clone(), 0.0), &Gate::I(radix));
+            let t = TensorGate::new(vec![Gate::H(radix), Gate::Z(2)]);
+            let expected = Gate::from(t.clone()).inverse();
+            assert_same_unitary(&power(Gate::from(t), -1.0), &expected);
+        }
+    }
+
+    #[test]
+    fn test_x_power_is_permutation() {
+        for radix in 2..=5 {
+            for k in -3..=3 {
+                assert!(power(Gate::X(radix), k as f64).is_permutation());
+            }
+        }
+    }
+
+    #[test]
+    fn test_real_powers() {
+        assert_same_unitary(&power(Gate::Z(2), 0.5), &Gate::S());
+        assert_same_unitary(&power(Gate::S(), 0.5), &Gate::T());
+        assert_same_unitary(&power(Gate::X(2), 0.5), &Gate::SX());
+
+        for radix in 2..=5 {
+            let root = power(Gate::X(radix), 1.0 / radix as f64);
+            assert_same_unitary(&repeated(root, radix), &Gate::X(radix));
+        }
+
+        let sqrt_swap = power(Gate::Swap(3), 0.5);
+        assert_same_unitary(&repeated(sqrt_swap, 2), &Gate::Swap(3));
+
+        let cx = crate::ControlledGate::from_gate(Gate::X(2), radices![2], vec![vec![1]]);
+        let cx = Gate::from(cx);
+        let cx_cubert = power(cx.clone(), 1.0 / 3.0);
+        assert_same_unitary(&repeated(cx_cubert, 3), &cx);
+    }
+
+    #[test]
+    fn test_real_power_of_involution_is_principal() {
+        // XZ squares to -I, with eigenvalues ±i. The principal square root
+        // takes them to e^(±iπ/4), which is the rotation by π/4.
+        let xz = Gate::from(WeylGate::unphased(2, 1, 1));
+        let unitary: UnitaryMatrix<c64> = power(xz, 0.5).gen_expr().get_unitary(&[]);
+        let s = 1.0 / 2.0_f64.sqrt();
+        crate::assert_unitary_close!(unitary, [
+            [s, -s],
+            [s, s],
+        ]);
+    }
+
+    #[test]
+    fn test_repeated_squaring_matches_repeated_products() {
+        let gates = [Gate::T(), Gate::from(TensorGate::new(vec![Gate::H(3), Gate::S()]))];
+        for gate in gates {
+            for k in 2..=6 {
+                let expected = repeated(gate.clone(), k);
+                assert_same_unitary(&power(gate.clone(), k as f64), &expected);
+                let expected = repeated(gate.inverse(), k);
+                assert_same_unitary(&power(gate.clone(), -(k as f64)), &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_powers_of_involutions() {
+        let cx = crate::ControlledGate::from_gate(Gate::X(2), radices![2], vec![vec![1]]);
+        let cx = Gate::from(cx);
+        let identity = Gate::from(TensorGate::new(vec![Gate::I(2), Gate::I(2)]));
+        assert_same_unitary(&power(cx.clone(), 1e12), &identity);
+        assert_same_unitary(&power(cx.clone(), 1e12 + 1.0), &cx);
+    }
+
+    #[test]
+    fn test_parameterized_powers() {
+        // Every copy takes the same phases, so P(θ)^k = P(kθ).
+        let theta = [0.3, 1.1];
+        for k in [-3, -2, 2, 3, 5] {
+            let gate = power(Gate::P(3), k as f64);
+            assert_eq!(gate.param_names().len(), gate.num_params());
+            let params: Vec<f64> = theta.iter().cycle().take(gate.num_params()).copied().collect();
+            let scaled: Vec<f64> = theta.iter().map(|t| k as f64 * t).collect();
+            let a: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&params);
+            let b: UnitaryMatrix<c64> = Gate::P(3).gen_expr().get_unitary(&scaled);
+            assert_unitaries_close(&a, &b, 1e-8);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_large_power() {
+        PowerGate::new(TensorGate::new(vec![Gate::H(3), Gate::S()]), 1e7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unknown_spectrum() {
+        PowerGate::new(Gate::H(3), 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_real_power_of_parameterized_gate() {
+        PowerGate::new(Gate::P(3), 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radix_changing_gate() {
+        PowerGate::new(crate::SwapGate::new_mixed(2, 3), 2.0);
+    }
+}
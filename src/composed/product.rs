@@ -0,0 +1,375 @@
+use qudit_core::{HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::utils::radices_to_vec;
+use crate::Gate;
+use crate::ParamSpec;
+use crate::PermutedGate;
+use crate::QuditGate;
+use crate::TensorGate;
+
+/// Several gates applied in sequence to a system of qudits, fused into one.
+///
+/// Each gate $G_k$ is applied to a list of locations in the system, and is
+/// padded with the identity on every other qudit to give $\hat{G}_k$. The
+/// product gate applies the gates in order:
+///
+/// $$
+/// U = \hat{G}_{n-1} \cdots \hat{G}_1 \hat{G}_0
+/// $$
+///
+/// The parameters of the product gate are the parameters of each gate, in
+/// the order the gates are applied. For example, the fused block
+/// $CNOT \cdot (U3 \otimes U3)$ takes the three parameters of the U3 gate
+/// on qudit 0, followed by the three parameters of the U3 gate on qudit 1.
+/// The inverse of a product keeps the parameters of the product it
+/// inverts; see [`ProductGate::inverse`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ProductGate {
+    // The number of levels of each qudit in the system.
+    radices: QuditRadices,
+
+    // The gates, in the order they are applied, with their locations.
+    gates: Vec<(Gate, Vec<usize>)>,
+
+    // The expression of the product gate.
+    expr: UnitaryExpression,
+
+    // Whether the parameters are in the reverse of the order the gates are
+    // applied, as in the inverse of a product.
+    params_reversed: bool,
+}
+
+impl ProductGate {
+    /// Construct a ProductGate.
+    ///
+    /// # Arguments
+    ///
+    /// * `radices` - The number of levels of each qudit in the system.
+    ///
+    /// * `gates` - The gates, in the order they are applied, each paired
+    ///   with the qudits it acts on. Location `i` of a gate's list is the
+    ///   qudit that the gate's `i`-th qudit is applied to.
+    ///
+    /// # Returns
+    ///
+    /// A new ProductGate.
+    ///
+    /// # Panics
+    ///
+    /// * If `gates` is empty.
+    ///
+    /// * If any gate's location list does not have one location per qudit
+    ///   of the gate.
+    ///
+    /// * If any location is out of range or repeated within a list.
+    ///
+    /// * If the radices of any gate do not match the radices of the qudits
+    ///   it is applied to.
+    ///
+    /// * If any gate changes the radices of its wires, e.g., a mixed-radix
+    ///   swap; see [`Gate::output_radices`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_core::{radices, HasParams, QuditSystem};
+    /// use qudit_gates::{Gate, ProductGate};
+    ///
+    /// // CNOT . (U3 ⊗ U3)
+    /// let block = ProductGate::new(radices![2, 2], vec![
+    ///     (Gate::U3(), vec![0]),
+    ///     (Gate::U3(), vec![1]),
+    ///     (Gate::CX(), vec![0, 1]),
+    /// ]);
+    /// assert_eq!(block.radices(), radices![2, 2]);
+    /// assert_eq!(block.num_params(), 6);
+    /// ```
+    pub fn new(radices: QuditRadices, gates: Vec<(Gate, Vec<usize>)>) -> Self {
+        Self::with_param_order(radices, gates, false)
+    }
+
+    fn with_param_order(
+        radices: QuditRadices,
+        gates: Vec<(Gate, Vec<usize>)>,
+        params_reversed: bool,
+    ) -> Self {
+        if gates.is_empty() {
+            panic!("Expected at least one gate in the product.");
+        }
+
+        let system_radices = radices_to_vec(&radices);
+        for (gate, location) in gates.iter() {
+            check_location(gate, location, &system_radices);
+        }
+
+        // `dot` concatenates parameters from left to right, so the product
+        // is built transposed, G_0^T G_1^T ... G_{n-1}^T, and transposed
+        // back. This keeps the parameters in the order the gates are applied.
+        // For reversed parameters, G_{n-1} ... G_1 G_0 is built directly.
+        let exprs = gates
+            .iter()
+            .map(|(gate, location)| pad_to_system(gate, location, &system_radices));
+        let expr = if params_reversed {
+            let mut exprs = exprs.rev();
+            let first = exprs.next().unwrap();
+            exprs.fold(first, |acc, expr| acc.dot(&expr))
+        } else {
+            let mut exprs = exprs;
+            let first = exprs.next().unwrap().transpose();
+            exprs.fold(first, |acc, expr| acc.dot(&expr.transpose())).transpose()
+        };
+
+        ProductGate { radices, gates, expr, params_reversed }
+    }
+
+    /// The gates, in the order they are applied, with their locations.
+    pub fn gates(&self) -> &[(Gate, Vec<usize>)] {
+        &self.gates
+    }
+
+    /// Construct the inverse of this ProductGate.
+    ///
+    /// The inverse of a product is the product of the inverses of each
+    /// gate, applied in reverse order. Its parameters are those of this
+    /// gate, in the same order, so the inverse of $CNOT \cdot (U3 \otimes
+    /// U3)$ still takes the parameters of the U3 gate on qudit 0 first.
+    pub fn inverse(&self) -> Self {
+        let gates = self
+            .gates
+            .iter()
+            .rev()
+            .map(|(gate, location)| (gate.inverse(), location.clone()))
+            .collect();
+        Self::with_param_order(self.radices.clone(), gates, !self.params_reversed)
+    }
+
+    /// The gates, in the order their parameters are taken.
+    fn gates_in_param_order(&self) -> Vec<&(Gate, Vec<usize>)> {
+        if self.params_reversed {
+            self.gates.iter().rev().collect()
+        } else {
+            self.gates.iter().collect()
+        }
+    }
+}
+
+/// Check that `gate` can be applied to `location` of a system of qudits.
+fn check_location(gate: &Gate, location: &[usize], system_radices: &[usize]) {
+    // The wires keep their radices for the whole product, so every gate
+    // must leave the radices of its wires unchanged.
+    if gate.output_radices() != gate.radices() {
+        panic!("Expected gate output radices to match its input radices.");
+    }
+
+    if location.len() != gate.num_qudits() {
+        panic!("Expected one location per qudit of the gate.");
+    }
+
+    if location.iter().any(|qudit| *qudit >= system_radices.len()) {
+        panic!("Expected locations to be less than the number of qudits.");
+    }
+
+    let mut sorted = location.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    if sorted.len() != location.len() {
+        panic!("Expected locations to be unique.");
+    }
+
+    if radices_to_vec(&gate.radices())
+        .iter()
+        .zip(location.iter())
+        .any(|(radix, qudit)| *radix != system_radices[*qudit])
+    {
+        panic!("Expected gate radices to match the radices of its location.");
+    }
+}
+
+/// Pad `gate` with the identity to act on the whole system at `location`.
+fn pad_to_system(
+    gate: &Gate,
+    location: &[usize],
+    system_radices: &[usize],
+) -> UnitaryExpression {
+    let identity = |qudit: usize| Gate::I(system_radices[qudit]);
+    let is_contiguous = location.windows(2).all(|pair| pair[1] == pair[0] + 1);
+
+    if is_contiguous {
+        // I ⊗ G ⊗ I needs no reordering of the wires.
+        let start = location[0];
+        let end = start + location.len();
+        let mut gates: Vec<Gate> = (0..start).map(identity).collect();
+        gates.push(gate.clone());
+        gates.extend((end..system_radices.len()).map(identity));
+        return TensorGate::new(gates).gen_expr();
+    }
+
+    // Place the gate first, followed by the identity on every other qudit
+    // in order, then move each qudit onto its wire.
+    let others: Vec<usize> =
+        (0..system_radices.len()).filter(|qudit| !location.contains(qudit)).collect();
+    let mut gates = vec![gate.clone()];
+    gates.extend(others.iter().map(|qudit| identity(*qudit)));
+    let perm: Vec<usize> = location.iter().chain(others.iter()).copied().collect();
+
+    PermutedGate::new(TensorGate::new(gates), perm).gen_expr()
+}
+
+impl HasParams for ProductGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        self.expr.num_params()
+    }
+}
+
+impl QuditSystem for ProductGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        self.radices.clone()
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        self.radices.len()
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.expr.dimension()
+    }
+}
+
+impl UnitaryExpressionGenerator for ProductGate {
+    #[inline]
+    fn gen_expr(&self) -> UnitaryExpression {
+        self.expr.clone()
+    }
+}
+
+impl QuditGate for ProductGate {
+    fn name(&self) -> String {
+        let names: Vec<String> = self.gates.iter().map(|(gate, _)| gate.name()).collect();
+        format!("Product({})", names.join(", "))
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        self.gates_in_param_order().into_iter().flat_map(|(gate, _)| gate.param_names()).collect()
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        self.gates_in_param_order().into_iter().flat_map(|(gate, _)| gate.param_specs()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::{assert_gradient_matches_finite_difference, assert_unitaries_close};
+    use qudit_core::c64;
+    use qudit_core::radices;
+    use qudit_core::unitary::UnitaryFn;
+    use qudit_core::unitary::UnitaryMatrix;
+
+    #[test]
+    fn test_gates_are_applied_in_order() {
+        // X then CNOT maps |00> to |11>, whereas CNOT then X maps it to |10>.
+        let gate = ProductGate::new(radices![2, 2], vec![
+            (Gate::X(2), vec![0]),
+            (Gate::CX(), vec![0, 1]),
+        ]);
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(unitary, [
+            [0, 0, 1, 0],
+            [0, 0, 0, 1],
+            [0, 1, 0, 0],
+            [1, 0, 0, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_non_contiguous_location() {
+        // A CNOT from qudit 2 to qudit 0, across an idle qutrit.
+        let product = Gate::from(ProductGate::new(radices![2, 3, 2], vec![
+            (Gate::CX(), vec![2, 0]),
+        ]));
+        let expected = Gate::from(PermutedGate::new(
+            TensorGate::new(vec![Gate::CX(), Gate::I(3)]),
+            vec![2, 0, 1],
+        ));
+        assert!(product.is_equivalent(&expected, 1e-8));
+    }
+
+    #[test]
+    fn test_parameter_order() {
+        let gate = ProductGate::new(radices![2, 3], vec![
+            (Gate::U3(), vec![0]),
+            (Gate::P(3), vec![1]),
+        ]);
+        let specs: Vec<String> = gate.param_specs().into_iter().map(|s| s.name).collect();
+        assert_eq!(specs, vec!["θ0", "θ1", "θ2", "θ0", "θ1"]);
+
+        // The parameters of the first gate come first.
+        let tensor = TensorGate::new(vec![Gate::U3(), Gate::P(3)]);
+        let params = Gate::from(gate.clone()).random_params(&mut rand::thread_rng());
+        let a: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&params);
+        let b: UnitaryMatrix<c64> = tensor.gen_expr().get_unitary(&params);
+        assert_unitaries_close(&a, &b, 1e-10);
+    }
+
+    #[test]
+    fn test_product_gradient() {
+        let gate = ProductGate::new(radices![2, 2], vec![
+            (Gate::U3(), vec![0]),
+            (Gate::U3(), vec![1]),
+            (Gate::CX(), vec![1, 0]),
+            (Gate::P(2), vec![1]),
+        ]);
+        let params = Gate::from(gate.clone()).random_params(&mut rand::thread_rng());
+        assert_gradient_matches_finite_difference(&gate, &params, 1e-5);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let gate = ProductGate::new(radices![2, 3], vec![
+            (Gate::U3(), vec![0]),
+            (Gate::P(3), vec![1]),
+            (Gate::CSumGate(crate::CSumGate::new_mixed(2, 3)), vec![0, 1]),
+        ]);
+        let inverse = Gate::from(gate.clone()).inverse();
+        assert!(matches!(inverse, Gate::Product(_)));
+        assert_eq!(inverse.param_names(), gate.param_names());
+
+        // U^-1 U is the identity at every parameter point.
+        let params = Gate::from(gate.clone()).random_params(&mut rand::thread_rng());
+        let a: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&params);
+        let b: UnitaryMatrix<c64> = inverse.gen_expr().get_unitary(&params);
+        let dim = gate.dimension();
+        for i in 0..dim {
+            for j in 0..dim {
+                let mut entry = c64::new(0.0, 0.0);
+                for k in 0..dim {
+                    entry += b[(i, k)] * a[(k, j)];
+                }
+                let expected = c64::new(if i == j { 1.0 } else { 0.0 }, 0.0);
+                assert!((entry - expected).norm() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mismatched_radices() {
+        ProductGate::new(radices![2, 3], vec![(Gate::CX(), vec![0, 1])]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radix_changing_gate() {
+        // After the swap, wire 0 is a qutrit, so the X gate no longer fits.
+        ProductGate::new(radices![2, 3], vec![
+            (crate::SwapGate::new_mixed(2, 3).into(), vec![0, 1]),
+            (Gate::X(2), vec![0]),
+        ]);
+    }
+}
@@ -0,0 +1,170 @@
+use qudit_core::{HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::Gate;
+use crate::ParamSpec;
+use crate::QuditGate;
+
+/// Several gates applied in parallel.
+///
+/// Given gates $G_0, G_1, \ldots, G_{n-1}$, the tensor gate is:
+///
+/// $$
+/// G_0 \otimes G_1 \otimes \cdots \otimes G_{n-1}
+/// $$
+///
+/// The radices of the tensor gate are the radices of each gate, in order,
+/// and its parameters are the parameters of each gate, in order. For
+/// example, a layer of two U3 gates takes the three parameters of the first
+/// U3 gate followed by the three parameters of the second.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct TensorGate {
+    // The gates applied in parallel.
+    gates: Vec<Gate>,
+
+    // The expression of the tensor gate.
+    expr: UnitaryExpression,
+}
+
+impl TensorGate {
+    /// Construct a TensorGate.
+    ///
+    /// # Arguments
+    ///
+    /// * `gates` - The gates to apply in parallel, from the first qudit to
+    ///   the last.
+    ///
+    /// # Returns
+    ///
+    /// A new TensorGate.
+    ///
+    /// # Panics
+    ///
+    /// * If `gates` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_core::{radices, HasParams, QuditSystem};
+    /// use qudit_gates::{Gate, TensorGate};
+    ///
+    /// let layer = TensorGate::new(vec![Gate::U3(), Gate::H(3), Gate::U3()]);
+    /// assert_eq!(layer.radices(), radices![2, 3, 2]);
+    /// assert_eq!(layer.num_params(), 6);
+    /// ```
+    pub fn new(gates: Vec<Gate>) -> Self {
+        if gates.is_empty() {
+            panic!("Expected at least one gate to tensor together.");
+        }
+
+        let expr = gates[1..]
+            .iter()
+            .fold(gates[0].gen_expr(), |acc, gate| acc.otimes(&gate.gen_expr()));
+
+        TensorGate { gates, expr }
+    }
+
+    /// The gates applied in parallel, from the first qudit to the last.
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// Construct the inverse of this TensorGate.
+    ///
+    /// The inverse of a tensor product is the tensor product of the
+    /// inverses of each gate.
+    pub fn inverse(&self) -> Self {
+        TensorGate::new(self.gates.iter().map(|gate| gate.inverse()).collect())
+    }
+}
+
+impl HasParams for TensorGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        self.expr.num_params()
+    }
+}
+
+impl QuditSystem for TensorGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        self.expr.radices()
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        self.expr.num_qudits()
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.expr.dimension()
+    }
+}
+
+impl UnitaryExpressionGenerator for TensorGate {
+    #[inline]
+    fn gen_expr(&self) -> UnitaryExpression {
+        self.expr.clone()
+    }
+}
+
+impl QuditGate for TensorGate {
+    fn name(&self) -> String {
+        let names: Vec<String> = self.gates.iter().map(|gate| gate.name()).collect();
+        format!("Tensor({})", names.join(", "))
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        self.gates.iter().flat_map(|gate| gate.param_names()).collect()
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        self.gates.iter().flat_map(|gate| gate.param_specs()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::assert_unitaries_close;
+    use qudit_core::c64;
+    use qudit_core::radices;
+    use qudit_core::unitary::UnitaryFn;
+    use qudit_core::unitary::UnitaryMatrix;
+
+    #[test]
+    fn test_x_tensor_z() {
+        let gate = TensorGate::new(vec![Gate::X(2), Gate::Z(2)]);
+        assert_eq!(gate.radices(), radices![2, 2]);
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(unitary, [
+            [0, 0, 1, 0],
+            [0, 0, 0, -1],
+            [1, 0, 0, 0],
+            [0, -1, 0, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_params_are_concatenated() {
+        let gate = TensorGate::new(vec![Gate::P(3), Gate::H(2), Gate::U3()]);
+        assert_eq!(gate.num_params(), 5);
+        assert_eq!(gate.param_specs().len(), 5);
+
+        // Setting only the second P phase matches P(0, θ) ⊗ H ⊗ U3(0, 0, 0).
+        let params = [0.0, 1.3, 0.0, 0.0, 0.0];
+        let expected = TensorGate::new(vec![Gate::P(3), Gate::H(2), Gate::I(2)]);
+        let a: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&params);
+        let b: UnitaryMatrix<c64> = expected.gen_expr().get_unitary(&params[..2]);
+        assert_unitaries_close(&a, &b, 1e-10);
+    }
+
+    #[test]
+    fn test_single_gate() {
+        let gate = TensorGate::new(vec![Gate::CSUM(3)]);
+        let a: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        let b: UnitaryMatrix<c64> = Gate::CSUM(3).gen_expr().get_unitary(&[]);
+        assert_unitaries_close(&a, &b, 1e-10);
+    }
+}
@@ -0,0 +1,214 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::QuditGate;
+
+/// The two-qudit controlled-sum (CSUM) gate. This is a Clifford gate.
+///
+/// The CSUM gate is the standard qudit generalization of the CNOT gate. It
+/// adds the state of the control qudit to the state of the target qudit:
+///
+/// $$
+/// CSUM = \sum_{a, b} \ket{a, b + ka \bmod d_t}\bra{a, b}
+/// $$
+///
+/// where $d_t$ is the number of levels of the target qudit and $k$ is a
+/// multiplier, usually one. Unlike a [`crate::ControlledGate`] wrapping an
+/// [`crate::XGate`], every level of the control qudit is active: the
+/// control level $a$ applies $X^{ka}$ to the target.
+///
+/// The qubit (radix = 2) version is the CNOT gate:
+///
+/// $$
+/// \begin{pmatrix}
+///     1 & 0 & 0 & 0 \\\\
+///     0 & 1 & 0 & 0 \\\\
+///     0 & 0 & 0 & 1 \\\\
+///     0 & 0 & 1 & 0 \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// The control and target qudits may have a different number of levels, in
+/// which case the sum is taken modulo the number of levels of the target.
+///
+/// References:
+/// - <https://arxiv.org/pdf/quant-ph/0111080.pdf>
+/// - <https://www.frontiersin.org/articles/10.3389/fphy.2020.589504/full>
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct CSumGate {
+    pub control_radix: usize,
+    pub target_radix: usize,
+    pub multiplier: usize,
+}
+
+impl CSumGate {
+    /// Construct a CSUM gate acting on two qudits with the same radix.
+    pub fn new(radix: usize) -> Self {
+        Self::new_general(radix, radix, 1)
+    }
+
+    /// Construct a CSUM gate acting on two qudits with different radices.
+    pub fn new_mixed(control_radix: usize, target_radix: usize) -> Self {
+        Self::new_general(control_radix, target_radix, 1)
+    }
+
+    /// Construct a CSUM gate mapping $\ket{a, b}$ to $\ket{a, b + ka}$.
+    ///
+    /// The multiplier `k` is taken modulo `target_radix`.
+    pub fn new_general(
+        control_radix: usize,
+        target_radix: usize,
+        multiplier: usize,
+    ) -> Self {
+        Self {
+            control_radix,
+            target_radix,
+            multiplier: multiplier % target_radix,
+        }
+    }
+}
+
+impl UnitaryExpressionGenerator for CSumGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = format!(
+            "utry {}<{}, {}>()",
+            self.name(), self.control_radix, self.target_radix
+        );
+
+        let dim = self.control_radix * self.target_radix;
+        let mut body = "[".to_string();
+        for i in 0..dim {
+            body += "[";
+            let a_i = i / self.target_radix;
+            let b_i = i % self.target_radix;
+            for j in 0..dim {
+                let a_j = j / self.target_radix;
+                let b_j = j % self.target_radix;
+                let shifted = (b_j + self.multiplier * a_j) % self.target_radix;
+                if a_i == a_j && b_i == shifted {
+                    body += "1,";
+                } else {
+                    body += "0,";
+                }
+            }
+            body += "],";
+        }
+        body += "]";
+
+        UnitaryExpression::new(proto + "{" + &body + "}")
+    }
+}
+
+impl HasParams for CSumGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for CSumGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.control_radix, self.target_radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        2
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.control_radix * self.target_radix
+    }
+}
+
+impl QuditGate for CSumGate {
+    fn name(&self) -> String {
+        if self.multiplier == 1 {
+            "CSUM".to_string()
+        } else {
+            format!("CSUM_{}", self.multiplier)
+        }
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    use super::*;
+
+    #[test]
+    fn test_qubit_csum_gate() {
+        let utry: UnitaryMatrix<c64> = CSumGate::new(2).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0, 0, 0],
+            [0, 1, 0, 0],
+            [0, 0, 0, 1],
+            [0, 0, 1, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_qutrit_csum_gate() {
+        let utry: UnitaryMatrix<c64> = CSumGate::new(3).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 1, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 1, 0, 0, 0],
+            [0, 0, 0, 1, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 1, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 1, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 1],
+            [0, 0, 0, 0, 0, 0, 1, 0, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_mixed_csum_gates() {
+        // Input |ab> = 3a + b maps to |a, b + a mod 3>.
+        let utry: UnitaryMatrix<c64> = CSumGate::new_mixed(2, 3).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0],
+            [0, 0, 1, 0, 0, 0],
+            [0, 0, 0, 0, 0, 1],
+            [0, 0, 0, 1, 0, 0],
+            [0, 0, 0, 0, 1, 0],
+        ]);
+
+        // Input |ab> = 2a + b maps to |a, b + a mod 2>.
+        let utry: UnitaryMatrix<c64> = CSumGate::new_mixed(3, 2).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0],
+            [0, 0, 0, 1, 0, 0],
+            [0, 0, 1, 0, 0, 0],
+            [0, 0, 0, 0, 1, 0],
+            [0, 0, 0, 0, 0, 1],
+        ]);
+    }
+
+    #[test]
+    fn test_general_csum_gate() {
+        // Input |ab> = 3a + b maps to |a, b + 2a mod 3>.
+        let gate = CSumGate::new_general(2, 3, 2);
+        let utry: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0],
+            [0, 0, 1, 0, 0, 0],
+            [0, 0, 0, 0, 1, 0],
+            [0, 0, 0, 0, 0, 1],
+            [0, 0, 0, 1, 0, 0],
+        ]);
+        assert_eq!(CSumGate::new_general(2, 3, 5), gate);
+    }
+}
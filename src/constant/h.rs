@@ -1,5 +1,8 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
 use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
 
+use crate::QuditGate;
+
 /// The one-qudit Hadamard gate. This is a Clifford/Weyl-Heisenberg gate.
 ///
 /// The qubit (radix = 2) Hadamard gate is given by the following matrix:
@@ -39,17 +42,26 @@ impl HGate {
     pub fn new(radix: usize) -> Self {
         Self { radix }
     }
-}
 
-impl UnitaryExpressionGenerator for HGate {
-    fn gen_expr(&self) -> UnitaryExpression {
-        let proto = format!("utry H<{}>()", self.radix);
-        let mut body = "".to_string();
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The qubit Hadamard gate is self-inverse. For higher radices, the
+    /// inverse is given by conjugating the roots of unity:
+    ///
+    /// $$
+    /// H^\dagger = \frac{1}{\sqrt{d}} \sum_{ij} \omega^{-ij} \ket{i}\bra{j}
+    /// $$
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
         if self.radix == 2 {
-            body += "[[1/sqrt(2), 1/sqrt(2)], [1/sqrt(2), ~1/sqrt(2)]]";
-            return UnitaryExpression::new(proto + "{" + &body + "}");
+            return self.gen_expr();
         }
-        let omega = format!("e^(2*π*i/{})", self.radix);
+        let proto = format!("utry Hdg<{}>()", self.radix);
+        let omega = format!("e^(~2*π*i/{})", self.radix);
+        self.gen_dft_expr(proto, omega)
+    }
+
+    fn gen_dft_expr(&self, proto: String, omega: String) -> UnitaryExpression {
+        let mut body = "".to_string();
         let invsqrt = format!("1/sqrt({})", self.radix);
         body += invsqrt.as_str();
         body += " * ";
@@ -66,3 +78,82 @@ impl UnitaryExpressionGenerator for HGate {
         UnitaryExpression::new(proto + "{" + &body + "}")
     }
 }
+
+impl UnitaryExpressionGenerator for HGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = format!("utry H<{}>()", self.radix);
+        if self.radix == 2 {
+            let body = "[[1/sqrt(2), 1/sqrt(2)], [1/sqrt(2), ~1/sqrt(2)]]";
+            return UnitaryExpression::new(proto + "{" + body + "}");
+        }
+        let omega = format!("e^(2*π*i/{})", self.radix);
+        self.gen_dft_expr(proto, omega)
+    }
+}
+
+impl HasParams for HGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for HGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix
+    }
+}
+
+impl QuditGate for HGate {
+    fn name(&self) -> String {
+        "H".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(feature = "proptest")]
+pub mod strategies {
+    use proptest::prelude::*;
+    use proptest::strategy::BoxedStrategy;
+
+    use super::*;
+    use crate::strategies::{ArbitraryGateWithRadices, RadixBounds};
+    use crate::utils::radices_to_vec;
+    use crate::Gate;
+
+    impl Arbitrary for HGate {
+        type Parameters = RadixBounds;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(bounds: Self::Parameters) -> Self::Strategy {
+            bounds.radix().prop_map(HGate::new).boxed()
+        }
+    }
+
+    impl ArbitraryGateWithRadices for HGate {
+        fn arbitrary_with_radices(radices: QuditRadices) -> Option<BoxedStrategy<Gate>> {
+            Self::arbitrary_with_radices_no_rec(radices)
+        }
+
+        fn arbitrary_with_radices_no_rec(radices: QuditRadices) -> Option<BoxedStrategy<Gate>> {
+            match radices_to_vec(&radices)[..] {
+                [radix] => Some(Just(Gate::H(radix)).boxed()),
+                _ => None,
+            }
+        }
+    }
+}
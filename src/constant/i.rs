@@ -1,5 +1,8 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
 use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
 
+use crate::QuditGate;
+
 /// The identity or no-op gate.
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
 pub struct IGate {
@@ -33,3 +36,37 @@ impl UnitaryExpressionGenerator for IGate {
         UnitaryExpression::new(proto + "{" + &body + "}")
     }
 }
+
+impl HasParams for IGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for IGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix
+    }
+}
+
+impl QuditGate for IGate {
+    fn name(&self) -> String {
+        "I".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
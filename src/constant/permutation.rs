@@ -0,0 +1,234 @@
+use qudit_core::{HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::utils::{compress_levels, expand_index, radices_from_slice, radices_to_vec};
+use crate::QuditGate;
+
+/// A gate that reorders the qudits of a system by an arbitrary permutation.
+///
+/// The qudit on input wire $i$ is moved to output wire $\pi(i)$, so that:
+///
+/// $$
+/// P_\pi \ket{l_0 l_1 \ldots l_{n-1}} = \ket{l_{\pi^{-1}(0)} l_{\pi^{-1}(1)}
+/// \ldots l_{\pi^{-1}(n-1)}}
+/// $$
+///
+/// When the permuted qudits have different radices, the gate maps between
+/// spaces with different qudit orderings; its radices are its input
+/// radices, and [`PermutationGate::output_radices`] gives the radices
+/// after the permutation. A two-qudit permutation `[1, 0]` is equivalent to
+/// a [`crate::SwapGate`].
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct PermutationGate {
+    // The radices of the input qudits.
+    radices: QuditRadices,
+
+    // The output wire of each input wire.
+    perm: Vec<usize>,
+}
+
+impl PermutationGate {
+    /// Construct a PermutationGate.
+    ///
+    /// # Arguments
+    ///
+    /// * `radices` - The number of levels of each input qudit.
+    ///
+    /// * `perm` - The output wire of each input wire.
+    ///
+    /// # Panics
+    ///
+    /// * If `radices` and `perm` have different lengths.
+    ///
+    /// * If `perm` is not a permutation of `0..radices.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_core::{radices, QuditSystem};
+    /// use qudit_gates::PermutationGate;
+    ///
+    /// // Cycle the first qudit to the end of the system.
+    /// let gate = PermutationGate::new(radices![2, 3, 4], vec![2, 0, 1]);
+    /// assert_eq!(gate.radices(), radices![2, 3, 4]);
+    /// assert_eq!(gate.output_radices(), radices![3, 4, 2]);
+    /// ```
+    pub fn new(radices: QuditRadices, perm: Vec<usize>) -> Self {
+        if radices.len() != perm.len() {
+            panic!("radices and perm must have the same length");
+        }
+
+        let mut sorted = perm.clone();
+        sorted.sort();
+        if sorted.into_iter().enumerate().any(|(i, wire)| i != wire) {
+            panic!("Expected perm to be a permutation of the qudit indices.");
+        }
+
+        PermutationGate { radices, perm }
+    }
+
+    /// The output wire of each input wire.
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// The radices of the qudits after the permutation.
+    pub fn output_radices(&self) -> QuditRadices {
+        let input_radices = radices_to_vec(&self.radices);
+        let mut output_radices = vec![0; self.perm.len()];
+        for (wire, radix) in self.perm.iter().zip(input_radices) {
+            output_radices[*wire] = radix;
+        }
+        radices_from_slice(&output_radices)
+    }
+
+    /// Construct the inverse of this PermutationGate.
+    ///
+    /// The inverse moves each qudit back to its original wire, so it acts
+    /// on this gate's output radices.
+    pub fn inverse(&self) -> Self {
+        let mut inverse_perm = vec![0; self.perm.len()];
+        for (i, wire) in self.perm.iter().enumerate() {
+            inverse_perm[*wire] = i;
+        }
+        PermutationGate::new(self.output_radices(), inverse_perm)
+    }
+}
+
+impl UnitaryExpressionGenerator for PermutationGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let input_radices = radices_to_vec(&self.radices);
+        let output_radices = radices_to_vec(&self.output_radices());
+        let dim = self.dimension();
+
+        // The row of the single one in each column.
+        let mut targets = vec![0; dim];
+        for (col, target) in targets.iter_mut().enumerate() {
+            let levels = expand_index(col, &input_radices);
+            let mut permuted = vec![0; levels.len()];
+            for (wire, level) in self.perm.iter().zip(levels) {
+                permuted[*wire] = level;
+            }
+            *target = compress_levels(&permuted, &output_radices);
+        }
+
+        gen_permutation_matrix_expr("Permutation", &input_radices, &targets)
+    }
+}
+
+/// Build the expression for a permutation matrix over `radices`.
+///
+/// Column `j` of the matrix has a single one, on row `targets[j]`.
+pub(crate) fn gen_permutation_matrix_expr(
+    name: &str,
+    radices: &[usize],
+    targets: &[usize],
+) -> UnitaryExpression {
+    let radix_list: Vec<String> = radices.iter().map(|r| r.to_string()).collect();
+    let proto = format!("utry {}<{}>()", name, radix_list.join(", "));
+    let mut body = "".to_string();
+    body += "[";
+    for i in 0..targets.len() {
+        body += "[";
+        for target in targets.iter() {
+            if *target == i {
+                body += "1,";
+            } else {
+                body += "0,";
+            }
+        }
+        body += "],";
+    }
+    body += "]";
+
+    UnitaryExpression::new(proto + "{" + &body + "}")
+}
+
+impl HasParams for PermutationGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for PermutationGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        self.radices.clone()
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        self.perm.len()
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        radices_to_vec(&self.radices).iter().product()
+    }
+}
+
+impl QuditGate for PermutationGate {
+    fn name(&self) -> String {
+        "Permutation".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::assert_unitaries_close;
+    use crate::SwapGate;
+    use qudit_core::c64;
+    use qudit_core::radices;
+    use qudit_core::unitary::UnitaryFn;
+    use qudit_core::unitary::UnitaryMatrix;
+
+    #[test]
+    fn test_two_qudit_permutation_is_swap() {
+        for (left, right) in [(2, 2), (3, 3), (2, 3), (3, 2)] {
+            let perm = PermutationGate::new(radices![left, right], vec![1, 0]);
+            let swap = SwapGate::new_mixed(left, right);
+            assert_eq!(perm.output_radices(), swap.output_radices());
+            let perm: UnitaryMatrix<c64> = perm.gen_expr().get_unitary(&[]);
+            let swap: UnitaryMatrix<c64> = swap.gen_expr().get_unitary(&[]);
+            assert_unitaries_close(&perm, &swap, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_qubit_cycle() {
+        // |abc> -> |cab>
+        let gate = PermutationGate::new(radices![2, 2, 2], vec![1, 2, 0]);
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(unitary, [
+            [1, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 1, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 1, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 1, 0],
+            [0, 1, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 1, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 1, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 1],
+        ]);
+    }
+
+    #[test]
+    fn test_inverse_permutation() {
+        let gate = PermutationGate::new(radices![2, 3, 4], vec![2, 0, 1]);
+        let inverse = gate.inverse();
+        assert_eq!(inverse.radices(), gate.output_radices());
+        assert_eq!(inverse.perm(), &[1, 2, 0]);
+        assert_eq!(inverse.output_radices(), gate.radices());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_permutation() {
+        PermutationGate::new(radices![2, 2], vec![0, 0]);
+    }
+}
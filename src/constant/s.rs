@@ -0,0 +1,148 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::QuditGate;
+
+/// The single-qubit S (phase) gate. This is a Clifford gate.
+///
+/// The S gate is the square root of the Pauli-Z gate and is given by the
+/// following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     1 & 0 \\\\
+///     0 & i \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.SGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SGate;
+
+impl UnitaryExpressionGenerator for SGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry S()";
+        let body = "[[1, 0], [0, i]]";
+        UnitaryExpression::new(proto.to_owned() + "{" + body + "}")
+    }
+}
+
+impl HasParams for SGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for SGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+impl QuditGate for SGate {
+    fn name(&self) -> String {
+        "S".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// The single-qubit S-dagger gate. This is a Clifford gate.
+///
+/// The Sdg gate is the inverse of the [`SGate`] and is given by the
+/// following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     1 & 0 \\\\
+///     0 & -i \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.SdgGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SdgGate;
+
+impl UnitaryExpressionGenerator for SdgGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry Sdg()";
+        let body = "[[1, 0], [0, ~i]]";
+        UnitaryExpression::new(proto.to_owned() + "{" + body + "}")
+    }
+}
+
+impl HasParams for SdgGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for SdgGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+impl QuditGate for SdgGate {
+    fn name(&self) -> String {
+        "Sdg".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    use super::*;
+
+    #[test]
+    fn test_s_gate() {
+        let utry: UnitaryMatrix<c64> = SGate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0],
+            [0, c64::new(0.0, 1.0)],
+        ]);
+    }
+
+    #[test]
+    fn test_sdg_gate() {
+        let utry: UnitaryMatrix<c64> = SdgGate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0],
+            [0, c64::new(0.0, -1.0)],
+        ]);
+    }
+}
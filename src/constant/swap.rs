@@ -1,5 +1,8 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
 use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
 
+use crate::QuditGate;
+
 /// The qudit swap gate. This is a two-qudit Clifford/Weyl-Heisenberg gate
 /// that swaps the state of two qudits.
 ///
@@ -39,33 +42,106 @@ use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
 /// where $d$ is the number of levels (2 levels is a qubit, 3 levels is a
 /// qutrit, etc.)
 ///
+/// Qudits of different radices can also be swapped; see
+/// [`SwapGate::new_mixed`]. In that case the gate maps a $d_1 \times d_2$
+/// space to a $d_2 \times d_1$ space, so its output radices are the
+/// reverse of its input radices.
+///
 /// References:
 /// - <https://link.springer.com/article/10.1007/s11128-013-0621-x>
 /// - <https://arxiv.org/pdf/1105.5485.pdf>
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
 pub struct SwapGate {
-    pub radix: usize,
+    /// The number of levels of the first input qudit.
+    pub left_radix: usize,
+
+    /// The number of levels of the second input qudit.
+    pub right_radix: usize,
 }
 
 impl SwapGate {
+    /// Construct a SwapGate between two qudits with `radix` levels.
     pub fn new(radix: usize) -> Self {
-        Self { radix }
+        Self::new_mixed(radix, radix)
+    }
+
+    /// Construct a SwapGate between a qudit with `left_radix` levels and a
+    /// qudit with `right_radix` levels.
+    ///
+    /// The gate's radices are its input radices, `[left_radix,
+    /// right_radix]`; see [`SwapGate::output_radices`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_core::{radices, QuditSystem};
+    /// use qudit_gates::SwapGate;
+    ///
+    /// let swap = SwapGate::new_mixed(2, 3);
+    /// assert_eq!(swap.radices(), radices![2, 3]);
+    /// assert_eq!(swap.output_radices(), radices![3, 2]);
+    /// ```
+    pub fn new_mixed(left_radix: usize, right_radix: usize) -> Self {
+        Self { left_radix, right_radix }
+    }
+
+    /// Returns `Some(d)` if both swapped qudits have `d` levels, or `None`
+    /// if the gate swaps qudits with different radices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_gates::SwapGate;
+    ///
+    /// assert_eq!(SwapGate::new(3).radix(), Some(3));
+    /// assert_eq!(SwapGate::new_mixed(2, 3).radix(), None);
+    /// ```
+    #[inline]
+    pub fn radix(&self) -> Option<usize> {
+        if self.is_mixed() {
+            None
+        } else {
+            Some(self.left_radix)
+        }
+    }
+
+    /// Returns true if the swapped qudits have different radices.
+    #[inline]
+    pub fn is_mixed(&self) -> bool {
+        self.left_radix != self.right_radix
+    }
+
+    /// The radices of the qudits after the swap.
+    #[inline]
+    pub fn output_radices(&self) -> QuditRadices {
+        radices![self.right_radix, self.left_radix]
+    }
+
+    /// Construct the inverse of this SwapGate.
+    ///
+    /// The inverse swaps the qudits back, so it acts on this gate's output
+    /// radices.
+    pub fn inverse(&self) -> Self {
+        Self::new_mixed(self.right_radix, self.left_radix)
     }
 }
 
 impl UnitaryExpressionGenerator for SwapGate {
     fn gen_expr(&self) -> UnitaryExpression {
-        let proto = format!("utry Swap<{}, {}>()", self.radix, self.radix);
+        let proto = format!("utry Swap<{}, {}>()", self.left_radix, self.right_radix);
+        let dim = self.left_radix * self.right_radix;
         let mut body = "".to_string();
         body += "[";
-        for i in 0..self.radix {
+        for i in 0..dim {
             body += "[";
-            let a_i = i / self.radix;
-            let b_i = i % self.radix;
-            for j in 0..self.radix {
-                let a_j = j / self.radix;
-                let b_j = j % self.radix;
-                if a_i == b_j && b_i == a_j {
+            // Row i is the output state |b a>, with b from the right qudit.
+            let b_i = i / self.left_radix;
+            let a_i = i % self.left_radix;
+            for j in 0..dim {
+                // Column j is the input state |a b>.
+                let a_j = j / self.right_radix;
+                let b_j = j % self.right_radix;
+                if a_i == a_j && b_i == b_j {
                     body += "1,";
                 } else {
                     body += "0,";
@@ -79,153 +155,88 @@ impl UnitaryExpressionGenerator for SwapGate {
     }
 }
 
-// #[cfg(test)]
-// mod test {
-//     use qudit_core::matrix::mat;
-
-//     use super::*;
-//     use qudit_core::c64;
-//     use qudit_core::unitary::UnitaryMatrix;
-//     use qudit_core::unitary::UnitaryFn;
-
-//     #[test]
-//     fn test_qubit_swap_gate() {
-//         let gate = SwapGate::new(2);
-//         let unitary: UnitaryMatrix<c64> = gate.get_unitary(&[]);
-//         let correct = mat![
-//             [
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0)
-//             ]
-//         ];
-//         unitary.assert_close_to(&correct);
-//     }
-
-//     #[test]
-//     fn test_qutrit_swap_gate() {
-//         let gate = SwapGate::new(3);
-//         let unitary: UnitaryMatrix<c64> = gate.get_unitary(&[]);
-//         let correct = mat![
-//             [
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0)
-//             ],
-//             [
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(0.0, 0.0),
-//                 c64::new(1.0, 0.0)
-//             ],
-//         ];
-//         unitary.assert_close_to(&correct);
-//     }
-// }
+impl HasParams for SwapGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for SwapGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.left_radix, self.right_radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        2
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.left_radix * self.right_radix
+    }
+}
+
+impl QuditGate for SwapGate {
+    fn name(&self) -> String {
+        "Swap".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use qudit_core::c64;
+    use qudit_core::unitary::UnitaryMatrix;
+    use qudit_core::unitary::UnitaryFn;
+
+    #[test]
+    fn test_qubit_swap_gate() {
+        let gate = SwapGate::new(2);
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(unitary, [
+            [1, 0, 0, 0],
+            [0, 0, 1, 0],
+            [0, 1, 0, 0],
+            [0, 0, 0, 1],
+        ]);
+    }
+
+    #[test]
+    fn test_qutrit_swap_gate() {
+        let gate = SwapGate::new(3);
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(unitary, [
+            [1, 0, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 1, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 1, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 1, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 1, 0],
+            [0, 0, 1, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 1, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 1],
+        ]);
+    }
+
+    #[test]
+    fn test_mixed_swap_gate() {
+        let gate = SwapGate::new_mixed(2, 3);
+        let unitary: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+        // Input |ab> = 3a + b maps to output |ba> = 2b + a.
+        crate::assert_unitary_close!(unitary, [
+            [1, 0, 0, 0, 0, 0],
+            [0, 0, 0, 1, 0, 0],
+            [0, 1, 0, 0, 0, 0],
+            [0, 0, 0, 0, 1, 0],
+            [0, 0, 1, 0, 0, 0],
+            [0, 0, 0, 0, 0, 1],
+        ]);
+    }
+}
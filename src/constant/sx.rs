@@ -0,0 +1,149 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::QuditGate;
+
+/// The single-qubit square-root X gate. This is a Clifford gate.
+///
+/// The SX gate is given by the following matrix:
+///
+/// $$
+/// \frac{1}{2}
+/// \begin{pmatrix}
+///     1 + i & 1 - i \\\\
+///     1 - i & 1 + i \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.SXGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SXGate;
+
+impl UnitaryExpressionGenerator for SXGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry SX()";
+        let body = "1/2 * [[1+i, 1-i], [1-i, 1+i]]";
+        UnitaryExpression::new(proto.to_owned() + "{" + body + "}")
+    }
+}
+
+impl HasParams for SXGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for SXGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+impl QuditGate for SXGate {
+    fn name(&self) -> String {
+        "SX".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// The single-qubit inverse square-root X gate. This is a Clifford gate.
+///
+/// The SXdg gate is the inverse of the [`SXGate`] and is given by the
+/// following matrix:
+///
+/// $$
+/// \frac{1}{2}
+/// \begin{pmatrix}
+///     1 - i & 1 + i \\\\
+///     1 + i & 1 - i \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.SXdgGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SXdgGate;
+
+impl UnitaryExpressionGenerator for SXdgGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry SXdg()";
+        let body = "1/2 * [[1-i, 1+i], [1+i, 1-i]]";
+        UnitaryExpression::new(proto.to_owned() + "{" + body + "}")
+    }
+}
+
+impl HasParams for SXdgGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for SXdgGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+impl QuditGate for SXdgGate {
+    fn name(&self) -> String {
+        "SXdg".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    use super::*;
+
+    #[test]
+    fn test_sx_gate() {
+        let utry: UnitaryMatrix<c64> = SXGate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [c64::new(0.5, 0.5), c64::new(0.5, -0.5)],
+            [c64::new(0.5, -0.5), c64::new(0.5, 0.5)],
+        ]);
+    }
+
+    #[test]
+    fn test_sxdg_gate() {
+        let utry: UnitaryMatrix<c64> = SXdgGate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [c64::new(0.5, -0.5), c64::new(0.5, 0.5)],
+            [c64::new(0.5, 0.5), c64::new(0.5, -0.5)],
+        ]);
+    }
+}
@@ -0,0 +1,152 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::QuditGate;
+
+/// The single-qubit T gate.
+///
+/// The T gate is the fourth root of the Pauli-Z gate and is given by the
+/// following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     1 & 0 \\\\
+///     0 & \exp({i\frac{\pi}{4}}) \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// Together with the Clifford gates, the T gate forms a universal gate set.
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.TGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct TGate;
+
+impl UnitaryExpressionGenerator for TGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry T()";
+        let body = "[[1, 0], [0, e^(i*π/4)]]";
+        UnitaryExpression::new(proto.to_owned() + "{" + body + "}")
+    }
+}
+
+impl HasParams for TGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for TGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+impl QuditGate for TGate {
+    fn name(&self) -> String {
+        "T".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// The single-qubit T-dagger gate.
+///
+/// The Tdg gate is the inverse of the [`TGate`] and is given by the
+/// following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     1 & 0 \\\\
+///     0 & \exp({-i\frac{\pi}{4}}) \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.TdgGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct TdgGate;
+
+impl UnitaryExpressionGenerator for TdgGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry Tdg()";
+        let body = "[[1, 0], [0, e^(~i*π/4)]]";
+        UnitaryExpression::new(proto.to_owned() + "{" + body + "}")
+    }
+}
+
+impl HasParams for TdgGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for TdgGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+impl QuditGate for TdgGate {
+    fn name(&self) -> String {
+        "Tdg".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    use super::*;
+
+    #[test]
+    fn test_t_gate() {
+        let s = 1.0 / 2.0_f64.sqrt();
+        let utry: UnitaryMatrix<c64> = TGate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0],
+            [0, c64::new(s, s)],
+        ]);
+    }
+
+    #[test]
+    fn test_tdg_gate() {
+        let s = 1.0 / 2.0_f64.sqrt();
+        let utry: UnitaryMatrix<c64> = TdgGate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0],
+            [0, c64::new(s, -s)],
+        ]);
+    }
+}
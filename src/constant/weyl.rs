@@ -0,0 +1,227 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::QuditGate;
+
+/// The one-qudit Weyl-Heisenberg displacement gate.
+///
+/// The displacement operators generalize the Pauli group to qudits. They
+/// are built from the shift gate ([`crate::XGate`]) and the clock gate
+/// ([`crate::ZGate`]) as follows:
+///
+/// $$
+/// D_{a,b} = \omega^{ab/2} X^a Z^b
+/// $$
+///
+/// where
+///
+/// $$
+/// \omega = \exp\Big(\frac{2\pi i}{d}\Big)
+/// $$
+///
+/// and $d$ is the number of levels (2 levels is a qubit, 3 levels is a
+/// qutrit, etc.) The $d^2$ operators $D_{a,b}$ for $a, b \in \mathbb{Z}_d$
+/// form, up to phases, the generalized Pauli group.
+///
+/// For even $d$, the phase $\omega^{ab/2}$ is not a power of $\omega$,
+/// so some authors drop it. An unphased gate, $X^a Z^b$, can be
+/// constructed with [`WeylGate::unphased`].
+///
+/// References:
+/// - <https://arxiv.org/pdf/quant-ph/0410117.pdf>
+/// - <https://arxiv.org/pdf/2302.07966.pdf>
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct WeylGate {
+    pub radix: usize,
+    pub a: usize,
+    pub b: usize,
+    pub phased: bool,
+}
+
+impl WeylGate {
+    /// Construct the displacement gate $\omega^{ab/2} X^a Z^b$.
+    ///
+    /// The powers `a` and `b` are taken modulo `radix`.
+    pub fn new(radix: usize, a: usize, b: usize) -> Self {
+        Self { radix, a: a % radix, b: b % radix, phased: true }
+    }
+
+    /// Construct the displacement gate $X^a Z^b$ without the phase factor.
+    ///
+    /// The powers `a` and `b` are taken modulo `radix`.
+    pub fn unphased(radix: usize, a: usize, b: usize) -> Self {
+        Self { radix, a: a % radix, b: b % radix, phased: false }
+    }
+
+    /// Enumerate all $d^2$ displacement gates for the given radix.
+    ///
+    /// The gates are ordered lexicographically by `(a, b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_gates::WeylGate;
+    /// let paulis = WeylGate::all(2);
+    /// assert_eq!(paulis.len(), 4);
+    /// assert_eq!(paulis[2], WeylGate::new(2, 1, 0));
+    /// ```
+    pub fn all(radix: usize) -> Vec<Self> {
+        let mut gates = Vec::with_capacity(radix * radix);
+        for a in 0..radix {
+            for b in 0..radix {
+                gates.push(Self::new(radix, a, b));
+            }
+        }
+        gates
+    }
+
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is only a displacement up to a phase. For the unphased
+    /// gate, $(X^a Z^b)^\dagger = \omega^{ab} X^{-a} Z^{-b}$. For the phased
+    /// gate, the phase $\omega^{ab/2}$ is a $2d$-th root of unity, so once
+    /// $-a$ and $-b$ are reduced to $d - a$ and $d - b$,
+    /// $D_{a,b}^\dagger = (-1)^{d+a+b} D_{d-a,d-b}$ for nonzero $a$ and $b$.
+    /// The inverse is therefore generated directly.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        let name = format!("{}dg", self.name());
+        self.gen_displacement_expr(&name, true)
+    }
+
+    /// Whether the inverse of this gate is exactly the displacement gate
+    /// of the same kind with powers $d - a$ and $d - b$, reduced mod $d$.
+    pub(crate) fn has_displacement_inverse(&self) -> bool {
+        if self.a == 0 || self.b == 0 {
+            true
+        } else if self.phased {
+            (self.radix + self.a + self.b) % 2 == 0
+        } else {
+            (self.a * self.b) % self.radix == 0
+        }
+    }
+
+    fn gen_displacement_expr(&self, name: &str, dagger: bool) -> UnitaryExpression {
+        let proto = format!("utry {}<{}>()", name, self.radix);
+
+        // X^a Z^b |j> = ω^(bj) |j + a mod d>, the optional phase ω^(ab/2)
+        // is folded into the exponent by working with 2d-th roots of unity.
+        let mut body = "[".to_string();
+        for i in 0..self.radix {
+            body += "[";
+            for j in 0..self.radix {
+                let (row, col) = if dagger { (j, i) } else { (i, j) };
+                if (col + self.a) % self.radix != row {
+                    body += "0, ";
+                    continue;
+                }
+
+                let mut exponent = 2 * self.b * col;
+                if self.phased {
+                    exponent += self.a * self.b;
+                }
+                exponent %= 2 * self.radix;
+                if dagger {
+                    exponent = (2 * self.radix - exponent) % (2 * self.radix);
+                }
+
+                if exponent == 0 {
+                    body += "1, ";
+                } else {
+                    body += &format!("e^(π*i*{}/{}), ", exponent, self.radix);
+                }
+            }
+            body += "],";
+        }
+        body += "]";
+
+        UnitaryExpression::new(proto + "{" + &body + "}")
+    }
+}
+
+impl UnitaryExpressionGenerator for WeylGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        self.gen_displacement_expr(&self.name(), false)
+    }
+}
+
+impl HasParams for WeylGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for WeylGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix
+    }
+}
+
+impl QuditGate for WeylGate {
+    fn name(&self) -> String {
+        if self.phased {
+            format!("Weyl_{}_{}", self.a, self.b)
+        } else {
+            format!("XZ_{}_{}", self.a, self.b)
+        }
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    use super::*;
+
+    #[test]
+    fn test_qubit_weyl_gates() {
+        // D_{1,1} = iXZ is the Pauli-Y gate.
+        let utry: UnitaryMatrix<c64> = WeylGate::new(2, 1, 1).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [0, c64::new(0.0, -1.0)],
+            [c64::new(0.0, 1.0), 0],
+        ]);
+
+        let utry: UnitaryMatrix<c64> = WeylGate::unphased(2, 1, 1).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [0, -1],
+            [1, 0],
+        ]);
+    }
+
+    #[test]
+    fn test_qutrit_weyl_gates() {
+        let w = c64::new(-0.5, 3.0_f64.sqrt() / 2.0);
+        let utry: UnitaryMatrix<c64> = WeylGate::unphased(3, 1, 2).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [0, 0, w],
+            [1, 0, 0],
+            [0, w * w, 0],
+        ]);
+
+        // The phase of D_{1,1} is ω^(1/2) = e^(iπ/3).
+        let phase = c64::new(0.5, 3.0_f64.sqrt() / 2.0);
+        let utry: UnitaryMatrix<c64> = WeylGate::new(3, 1, 1).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [0, 0, phase * w * w],
+            [phase, 0, 0],
+            [0, phase * w, 0],
+        ]);
+    }
+}
@@ -1,5 +1,8 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
 use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
 
+use crate::QuditGate;
+
 /// The one-qudit shift (X) gate. This is a Weyl-Heisenberg gate.
 ///
 /// This gate shifts the state of a qudit up by one level modulo. For
@@ -45,7 +48,7 @@ impl UnitaryExpressionGenerator for XGate {
         for i in 0..self.radix {
             body += "[";
             for j in 0..self.radix {
-                if j + 1 % self.radix == i {
+                if (j + 1) % self.radix == i {
                     body += "1, ";
                 } else {
                     body += "0, ";
@@ -59,23 +62,54 @@ impl UnitaryExpressionGenerator for XGate {
     }
 }
 
+impl HasParams for XGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for XGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix
+    }
+}
+
+impl QuditGate for XGate {
+    fn name(&self) -> String {
+        "X".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use qudit_core::{c32, matrix::mat, unitary::UnitaryMatrix};
+    use qudit_core::{c32, unitary::UnitaryMatrix};
     use qudit_core::unitary::UnitaryFn;
 
     use super::*;
 
     #[test]
     fn test_qutrit_x_gate() {
-        let utry: UnitaryMatrix<c32> = XGate::new(3).gen_expr().get_unitary([]);
-        let expected = mat![
+        let utry: UnitaryMatrix<c32> = XGate::new(3).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
             [0, 0, 1],
             [1, 0, 0],
             [0, 1, 0]
-        ]; // TODO: Make a comparison function/macro for matrices
-        // The compiler error is because type inference uses the matrix integer elements
-        // to infer integers. This entire test paradigm should be greatly simplied...
-        assert_eq!(utry, expected);
+        ]);
     }
 }
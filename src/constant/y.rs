@@ -0,0 +1,79 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::QuditGate;
+
+/// The single-qubit Pauli-Y gate. This is a Clifford gate.
+///
+/// The Y gate is given by the following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     0 & -i \\\\
+///     i & 0 \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.YGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct YGate;
+
+impl UnitaryExpressionGenerator for YGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry Y()";
+        let body = "[[0, ~i], [i, 0]]";
+        UnitaryExpression::new(proto.to_owned() + "{" + body + "}")
+    }
+}
+
+impl HasParams for YGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for YGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+impl QuditGate for YGate {
+    fn name(&self) -> String {
+        "Y".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    use super::*;
+
+    #[test]
+    fn test_y_gate() {
+        let utry: UnitaryMatrix<c64> = YGate.gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [0, c64::new(0.0, -1.0)],
+            [c64::new(0.0, 1.0), 0],
+        ]);
+    }
+}
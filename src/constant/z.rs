@@ -0,0 +1,129 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
+use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+
+use crate::QuditGate;
+
+/// The one-qudit clock (Z) gate. This is a Weyl-Heisenberg gate.
+///
+/// This gate applies a phase to each level of a qudit, proportional to the
+/// level. For example, the clock gate on a qubit is the Pauli-Z gate. The
+/// clock gate on a qutrit is the following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     1 & 0 & 0 \\\\
+///     0 & \omega & 0 \\\\
+///     0 & 0 & \omega^2 \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// The clock gate is generally given by the following formula:
+///
+/// $$
+/// Z = \sum_a \omega^a \ket{a}\bra{a}
+/// $$
+///
+/// where
+///
+/// $$
+/// \omega = \exp\Big(\frac{2\pi i}{d}\Big)
+/// $$
+///
+/// and $d$ is the number of levels (2 levels is a qubit, 3 levels is a
+/// qutrit, etc.)
+///
+/// References:
+/// - <https://arxiv.org/pdf/2302.07966.pdf>
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct ZGate {
+    pub radix: usize,
+}
+
+impl ZGate {
+    pub fn new(radix: usize) -> Self {
+        Self { radix }
+    }
+}
+
+impl UnitaryExpressionGenerator for ZGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = format!("utry Z<{}>()", self.radix);
+        let mut body = "[".to_string();
+        for i in 0..self.radix {
+            body += "[";
+            for j in 0..self.radix {
+                if i == j {
+                    body += &format!("e^(2*π*i*{}/{}), ", i, self.radix);
+                } else {
+                    body += "0, ";
+                }
+            }
+            body += "],";
+        }
+        body += "]";
+
+        UnitaryExpression::new(proto + "{" + &body + "}")
+    }
+}
+
+impl HasParams for ZGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        0
+    }
+}
+
+impl QuditSystem for ZGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix
+    }
+}
+
+impl QuditGate for ZGate {
+    fn name(&self) -> String {
+        "Z".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    use super::*;
+
+    #[test]
+    fn test_qubit_z_gate() {
+        let utry: UnitaryMatrix<c64> = ZGate::new(2).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0],
+            [0, -1],
+        ]);
+    }
+
+    #[test]
+    fn test_qutrit_z_gate() {
+        let w = c64::new(-0.5, 3.0_f64.sqrt() / 2.0);
+        let utry: UnitaryMatrix<c64> = ZGate::new(3).gen_expr().get_unitary(&[]);
+        crate::assert_unitary_close!(utry, [
+            [1, 0, 0],
+            [0, w, 0],
+            [0, 0, w * w],
+        ]);
+    }
+}
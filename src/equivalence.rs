@@ -0,0 +1,201 @@
+use qudit_core::c64;
+use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+use qudit_core::HasParams;
+use qudit_core::QuditSystem;
+use qudit_expr::UnitaryExpressionGenerator;
+
+use crate::Gate;
+
+/// The number of random parameter points compared by
+/// [`Gate::is_equivalent`] when either gate is parameterized.
+const NUM_EQUIVALENCE_SAMPLES: usize = 8;
+
+impl Gate {
+    /// Returns true if this gate and `other` implement the same unitary up
+    /// to a global phase.
+    ///
+    /// Constant gates are compared directly. If either gate is
+    /// parameterized, both gates must take the same number of parameters;
+    /// they are then compared at several random parameter points, with the
+    /// same parameter vector passed to both gates. Use
+    /// [`Gate::is_equivalent_with`] if the parameters of `other` are not
+    /// identical to those of this gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The gate to compare against.
+    ///
+    /// * `tol` - The largest entry-wise difference allowed between the two
+    ///   unitaries after aligning their global phases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_gates::{DaggerGate, Gate, HGate};
+    ///
+    /// let h = Gate::H(2);
+    /// let hdg = Gate::from(DaggerGate::from_gate(HGate::new(2)));
+    /// assert!(h.is_equivalent(&hdg, 1e-8));
+    /// assert!(!h.is_equivalent(&Gate::X(2), 1e-8));
+    /// ```
+    pub fn is_equivalent(&self, other: &Gate, tol: f64) -> bool {
+        if self.num_params() != other.num_params() {
+            return false;
+        }
+        self.is_equivalent_with(other, |params| params.to_vec(), tol)
+    }
+
+    /// Returns true if this gate and `other` implement the same unitary up
+    /// to a global phase, given a mapping between their parameters.
+    ///
+    /// The gates are compared at several random parameter points of this
+    /// gate. At each point, `param_map` computes the corresponding
+    /// parameters of `other`.
+    ///
+    /// # Panics
+    ///
+    /// * If `param_map` does not produce exactly as many parameters as
+    ///   `other` takes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_gates::{DaggerGate, Gate, PGate};
+    ///
+    /// let p = Gate::P(3);
+    /// let pdg = Gate::from(DaggerGate::from_gate(PGate::new(3)));
+    /// let negate = |params: &[f64]| params.iter().map(|p| -p).collect();
+    /// assert!(p.is_equivalent_with(&pdg, negate, 1e-8));
+    /// ```
+    pub fn is_equivalent_with<F>(&self, other: &Gate, param_map: F, tol: f64) -> bool
+    where
+        F: Fn(&[f64]) -> Vec<f64>,
+    {
+        if self.radices() != other.radices() {
+            return false;
+        }
+
+        let num_samples = if self.is_constant() && other.is_constant() {
+            1
+        } else {
+            NUM_EQUIVALENCE_SAMPLES
+        };
+
+        let self_expr = self.gen_expr();
+        let other_expr = other.gen_expr();
+        let mut rng = rand::thread_rng();
+
+        (0..num_samples).all(|_| {
+            let params = self.random_params(&mut rng);
+            let other_params = param_map(&params);
+            if other_params.len() != other.num_params() {
+                panic!(
+                    "Expected parameter mapping to produce {} parameters.",
+                    other.num_params()
+                );
+            }
+            let a: UnitaryMatrix<c64> = self_expr.get_unitary(&params);
+            let b: UnitaryMatrix<c64> = other_expr.get_unitary(&other_params);
+            unitaries_equal_up_to_phase(&a, &b, tol)
+        })
+    }
+
+    /// Returns true if the gate takes no parameters.
+    #[inline]
+    fn is_constant(&self) -> bool {
+        self.num_params() == 0
+    }
+}
+
+/// Compare two unitaries of equal dimension up to a global phase.
+///
+/// The phase is aligned using the overlap $\mathrm{tr}(A^\dagger B)$, which
+/// is $d e^{i\phi}$ when $B = e^{i\phi} A$.
+fn unitaries_equal_up_to_phase(
+    a: &UnitaryMatrix<c64>,
+    b: &UnitaryMatrix<c64>,
+    tol: f64,
+) -> bool {
+    let dim = a.dimension();
+
+    let mut overlap = c64::new(0.0, 0.0);
+    for i in 0..dim {
+        for j in 0..dim {
+            overlap += a[(i, j)].conj() * b[(i, j)];
+        }
+    }
+
+    // Orthogonal unitaries cannot be aligned by any phase.
+    if overlap.norm() < f64::EPSILON * dim as f64 {
+        return false;
+    }
+    let phase = overlap / overlap.norm();
+
+    (0..dim).all(|i| (0..dim).all(|j| (b[(i, j)] - phase * a[(i, j)]).norm() <= tol))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ControlledGate;
+    use crate::DaggerGate;
+    use crate::HGate;
+    use crate::PGate;
+    use crate::XGate;
+    use qudit_core::radices;
+
+    #[test]
+    fn test_self_inverse_gates_equal_dagger() {
+        assert!(Gate::H(2).is_equivalent(&Gate::from(DaggerGate::from_gate(HGate::new(2))), 1e-8));
+        assert!(Gate::X(2).is_equivalent(&Gate::from(DaggerGate::from_gate(XGate::new(2))), 1e-8));
+    }
+
+    #[test]
+    fn test_controlled_x_equals_csum() {
+        for radix in 2..=4 {
+            let cx = Gate::from(ControlledGate::from_gate(
+                XGate::new(radix),
+                radices![radix],
+                vec![vec![1]],
+            ));
+            let csum = Gate::CSUM(radix);
+            assert_eq!(cx.is_equivalent(&csum, 1e-8), radix == 2);
+        }
+    }
+
+    #[test]
+    fn test_inverse_is_equivalent_to_dagger() {
+        let gates = vec![Gate::H(3), Gate::X(4), Gate::P(3), Gate::U3(), Gate::RZZ(3)];
+        for gate in gates {
+            let dagger = Gate::from(DaggerGate::from_gate(gate.clone()));
+            assert!(gate.inverse().is_equivalent(&dagger, 1e-8), "{:?}", gate);
+        }
+    }
+
+    #[test]
+    fn test_equivalent_up_to_global_phase() {
+        // RZ(θ) = e^{-iθ/2} P(θ) on a qubit.
+        assert!(Gate::RZ(2, (0, 1)).is_equivalent(&Gate::P(2), 1e-8));
+        assert!(Gate::Z(2).is_equivalent(&Gate::from(Gate::Z(2).gen_expr()), 1e-8));
+    }
+
+    #[test]
+    fn test_inequivalent_gates() {
+        assert!(!Gate::H(2).is_equivalent(&Gate::X(2), 1e-8));
+        assert!(!Gate::X(2).is_equivalent(&Gate::Z(2), 1e-8));
+        assert!(!Gate::H(2).is_equivalent(&Gate::H(3), 1e-8));
+        assert!(!Gate::P(2).is_equivalent(&Gate::U3(), 1e-8));
+        assert!(!Gate::P(3).is_equivalent(&Gate::from(DaggerGate::from_gate(PGate::new(3))), 1e-8));
+    }
+
+    #[test]
+    fn test_parameter_mapping() {
+        let p = Gate::P(3);
+        let pdg = Gate::from(DaggerGate::from_gate(PGate::new(3)));
+        let negate = |params: &[f64]| params.iter().map(|p| -p).collect();
+        assert!(p.is_equivalent_with(&pdg, negate, 1e-8));
+
+        let swapped = |params: &[f64]| vec![params[1], params[0]];
+        assert!(!p.is_equivalent_with(&p, swapped, 1e-8));
+    }
+}
@@ -0,0 +1,119 @@
+use std::f64::consts::PI;
+
+use qudit_core::{HasParams, QuditSystem};
+use qudit_expr::UnitaryExpressionGenerator;
+use rand::Rng;
+
+/// A quantum gate acting on a system of qudits.
+///
+/// A gate knows its name, the radices of the qudits it acts on, and its
+/// parameters without needing to generate its expression. This allows
+/// code to be generic over gates, only calling
+/// [`UnitaryExpressionGenerator::gen_expr`] when the unitary is needed.
+///
+/// # Examples
+///
+/// ```
+/// use qudit_core::{HasParams, QuditSystem};
+/// use qudit_gates::{Gate, QuditGate};
+///
+/// let gate = Gate::P(3);
+/// assert_eq!(gate.name(), "P");
+/// assert_eq!(gate.dimension(), 3);
+/// assert_eq!(gate.num_params(), 2);
+/// assert_eq!(gate.param_names(), vec!["θ0", "θ1"]);
+/// assert!(gate.is_parameterized());
+/// ```
+pub trait QuditGate: UnitaryExpressionGenerator + QuditSystem + HasParams {
+    /// The name of the gate.
+    ///
+    /// This is the name of the gate's generated expression.
+    fn name(&self) -> String;
+
+    /// The names of the gate's parameters, in order.
+    fn param_names(&self) -> Vec<String>;
+
+    /// The metadata of the gate's parameters, in order.
+    ///
+    /// By default, every parameter is treated as an angle with period
+    /// $2\pi$.
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        self.param_names().into_iter().map(ParamSpec::angle).collect()
+    }
+
+    /// Returns true if the gate takes any parameters.
+    #[inline]
+    fn is_parameterized(&self) -> bool {
+        self.num_params() > 0
+    }
+}
+
+/// Metadata describing a single gate parameter.
+///
+/// Every parameter takes values in the interval `[lower, upper)`. If the
+/// parameter is `periodic`, the gate's unitary repeats with period
+/// `upper - lower`, so values outside the interval can be wrapped back
+/// into it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamSpec {
+    pub name: String,
+    pub lower: f64,
+    pub upper: f64,
+    pub periodic: bool,
+}
+
+impl ParamSpec {
+    /// Construct a ParamSpec.
+    ///
+    /// # Panics
+    ///
+    /// * If `lower` is not less than `upper`.
+    pub fn new<S: Into<String>>(
+        name: S,
+        lower: f64,
+        upper: f64,
+        periodic: bool,
+    ) -> Self {
+        if lower >= upper {
+            panic!("Expected parameter lower bound to be less than upper bound.");
+        }
+        Self { name: name.into(), lower, upper, periodic }
+    }
+
+    /// Construct a ParamSpec for an angle with period $2\pi$.
+    pub fn angle<S: Into<String>>(name: S) -> Self {
+        Self::new(name, 0.0, 2.0 * PI, true)
+    }
+
+    /// Construct a ParamSpec for an angle with period $4\pi$.
+    ///
+    /// This is the period of half-angle rotations such as $RX(\theta)$,
+    /// which only repeat up to a global phase after $2\pi$.
+    pub fn half_angle<S: Into<String>>(name: S) -> Self {
+        Self::new(name, 0.0, 4.0 * PI, true)
+    }
+
+    /// The period of the parameter, if it is periodic.
+    pub fn period(&self) -> Option<f64> {
+        if self.periodic {
+            Some(self.upper - self.lower)
+        } else {
+            None
+        }
+    }
+
+    /// Wrap a periodic parameter value into `[lower, upper)`.
+    ///
+    /// Non-periodic parameter values are clamped to the bounds instead.
+    pub fn wrap(&self, value: f64) -> f64 {
+        match self.period() {
+            Some(period) => self.lower + (value - self.lower).rem_euclid(period),
+            None => value.clamp(self.lower, self.upper),
+        }
+    }
+
+    /// Sample a parameter value uniformly from `[lower, upper)`.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        rng.gen_range(self.lower..self.upper)
+    }
+}
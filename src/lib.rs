@@ -6,30 +6,83 @@ use qudit_core::radices;
 //use qudit_core::ComplexScalar;
 use qudit_core::HasParams;
 use qudit_core::QuditRadices;
+use qudit_core::QuditSystem;
 use qudit_expr::{UnitaryExpression, UnitaryExpressionGenerator};
+use rand::Rng;
+
+mod equivalence;
+pub mod gate;
+pub mod native;
+pub mod properties;
+mod utils;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+#[cfg(feature = "proptest")]
+pub mod strategies;
 
 pub mod constant {
+    pub mod csum;
     pub mod h;
     pub mod i;
+    pub mod permutation;
+    pub mod s;
     pub mod swap;
+    pub mod sx;
+    pub mod t;
+    pub mod weyl;
     pub mod x;
+    pub mod y;
+    pub mod z;
 }
 pub mod parameterized {
+    pub mod ising;
     pub mod p;
+    pub mod rotation;
     pub mod u3;
 }
 
 pub mod composed {
     pub mod control;
     pub mod dagger;
+    pub mod permute;
+    pub mod embed;
+    pub mod tensor;
+    pub mod product;
+    pub mod power;
+    pub mod fixed;
+    pub mod inverse;
 }
 
+pub use gate::{ParamSpec, QuditGate};
+pub use native::NativeUnitaryFn;
+pub use properties::GateProperties;
+pub use constant::csum::CSumGate;
 pub use constant::i::IGate;
+pub use constant::permutation::PermutationGate;
 pub use constant::h::HGate;
+pub use constant::s::{SGate, SdgGate};
+pub use constant::swap::SwapGate;
+pub use constant::sx::{SXGate, SXdgGate};
+pub use constant::t::{TGate, TdgGate};
+pub use constant::weyl::WeylGate;
 pub use constant::x::XGate;
+pub use constant::y::YGate;
+pub use constant::z::ZGate;
+pub use parameterized::ising::{RXXGate, RYYGate, RZXGate, RZZGate};
 pub use parameterized::p::PGate;
+pub use parameterized::rotation::{RXGate, RYGate, RZGate};
 pub use parameterized::u3::U3Gate;
 pub use composed::control::ControlledGate;
+pub use composed::dagger::DaggerGate;
+pub use composed::permute::PermutedGate;
+pub use composed::embed::EmbeddedGate;
+pub use composed::tensor::TensorGate;
+pub use composed::product::ProductGate;
+pub use composed::power::PowerGate;
+pub use composed::fixed::FixedParamGate;
+pub use composed::inverse::InverseGate;
 
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
 pub enum Gate {
@@ -37,11 +90,44 @@ pub enum Gate {
     PGate(PGate),
     XGate(XGate),
     U3Gate(U3Gate),
+    ZGate(ZGate),
+    WeylGate(WeylGate),
+    CSumGate(CSumGate),
+    YGate(YGate),
+    SGate(SGate),
+    SdgGate(SdgGate),
+    TGate(TGate),
+    TdgGate(TdgGate),
+    SXGate(SXGate),
+    SXdgGate(SXdgGate),
+    RXGate(RXGate),
+    RYGate(RYGate),
+    RZGate(RZGate),
+    RXXGate(RXXGate),
+    RYYGate(RYYGate),
+    RZZGate(RZZGate),
+    RZXGate(RZXGate),
+    SwapGate(SwapGate),
+    PermutationGate(PermutationGate),
+    IGate(IGate),
     Controlled(ControlledGate),
+    Dagger(DaggerGate),
+    Permuted(PermutedGate),
+    Embedded(EmbeddedGate),
+    Tensor(TensorGate),
+    Product(ProductGate),
+    Power(PowerGate),
+    Fixed(FixedParamGate),
+    Inverse(InverseGate),
     Expression(UnitaryExpression),
 }
 
 impl Gate {
+    #[allow(non_snake_case)]
+    pub fn I(radix: usize) -> Self {
+        Gate::IGate(IGate::new(radix))
+    }
+
     #[allow(non_snake_case)]
     pub fn H(radix: usize) -> Self {
         Gate::HGate(HGate::new(radix))
@@ -57,20 +143,270 @@ impl Gate {
         Gate::XGate(XGate::new(radix))
     }
 
+    #[allow(non_snake_case)]
+    pub fn Z(radix: usize) -> Self {
+        Gate::ZGate(ZGate::new(radix))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Weyl(radix: usize, a: usize, b: usize) -> Self {
+        Gate::WeylGate(WeylGate::new(radix, a, b))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Y() -> Self {
+        Gate::YGate(YGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn S() -> Self {
+        Gate::SGate(SGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Sdg() -> Self {
+        Gate::SdgGate(SdgGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn T() -> Self {
+        Gate::TGate(TGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Tdg() -> Self {
+        Gate::TdgGate(TdgGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn SX() -> Self {
+        Gate::SXGate(SXGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn SXdg() -> Self {
+        Gate::SXdgGate(SXdgGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn RX(radix: usize, levels: (usize, usize)) -> Self {
+        Gate::RXGate(RXGate::new(radix, levels))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn RY(radix: usize, levels: (usize, usize)) -> Self {
+        Gate::RYGate(RYGate::new(radix, levels))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn RZ(radix: usize, levels: (usize, usize)) -> Self {
+        Gate::RZGate(RZGate::new(radix, levels))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn RXX() -> Self {
+        Gate::RXXGate(RXXGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn RYY() -> Self {
+        Gate::RYYGate(RYYGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn RZZ(radix: usize) -> Self {
+        Gate::RZZGate(RZZGate::new(radix))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn RZX() -> Self {
+        Gate::RZXGate(RZXGate)
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Swap(radix: usize) -> Self {
+        Gate::SwapGate(SwapGate::new(radix))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn Permutation(radices: QuditRadices, perm: Vec<usize>) -> Self {
+        Gate::PermutationGate(PermutationGate::new(radices, perm))
+    }
+
     #[allow(non_snake_case)]
     pub fn CP() -> Self {
-        Gate::Controlled(ControlledGate::new(PGate::new(2), radices![2], vec![vec![1]]))
+        Gate::Controlled(ControlledGate::from_gate(PGate::new(2), radices![2], vec![vec![1]]))
     }
 
     #[allow(non_snake_case)]
     pub fn CX() -> Self {
-        Gate::Controlled(ControlledGate::new(XGate::new(2), radices![2], vec![vec![1]]))
+        Gate::Controlled(ControlledGate::from_gate(XGate::new(2), radices![2], vec![vec![1]]))
+    }
+
+    #[allow(non_snake_case)]
+    pub fn CSUM(radix: usize) -> Self {
+        Gate::CSumGate(CSumGate::new(radix))
     }
 
     #[allow(non_snake_case)]
     pub fn U3() -> Self {
         Gate::U3Gate(U3Gate)
     }
+
+    /// Sample a random parameter vector for this gate.
+    ///
+    /// Each parameter is sampled uniformly from the bounds given by its
+    /// [`ParamSpec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_gates::Gate;
+    /// let params = Gate::U3().random_params(&mut rand::thread_rng());
+    /// assert_eq!(params.len(), 3);
+    /// ```
+    pub fn random_params<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        self.param_specs().iter().map(|spec| spec.sample(rng)).collect()
+    }
+
+    /// The radices of the qudits after this gate is applied.
+    ///
+    /// These are the gate's [`QuditSystem::radices`], except for gates that
+    /// move qudits of different radices onto each other's wires, such as a
+    /// mixed-radix [`SwapGate`] or a [`PermutationGate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_core::radices;
+    /// use qudit_gates::{Gate, SwapGate};
+    ///
+    /// assert_eq!(Gate::CSUM(3).output_radices(), radices![3, 3]);
+    /// assert_eq!(Gate::from(SwapGate::new_mixed(2, 3)).output_radices(), radices![3, 2]);
+    /// ```
+    pub fn output_radices(&self) -> QuditRadices {
+        match self {
+            Gate::SwapGate(gate) => gate.output_radices(),
+            Gate::PermutationGate(gate) => gate.output_radices(),
+            Gate::Controlled(gate) => {
+                gate.control_radices().concat(&gate.gate().output_radices())
+            },
+            // The inverse maps the gate's outputs back to its inputs.
+            Gate::Dagger(gate) => gate.gate().radices(),
+            Gate::Inverse(gate) => gate.gate().radices(),
+            Gate::Tensor(gate) => {
+                let gates = gate.gates();
+                gates[1..].iter().fold(gates[0].output_radices(), |acc, gate| {
+                    acc.concat(&gate.output_radices())
+                })
+            },
+            Gate::Fixed(gate) => gate.gate().output_radices(),
+            _ => self.radices(),
+        }
+    }
+
+    /// Compute the inverse of this gate.
+    ///
+    /// Closed-form inverses are returned where they are known, so that,
+    /// for example, the inverse of a T gate is a Tdg gate. Gates whose
+    /// inverse is not another built-in gate are wrapped in an
+    /// [`InverseGate`], which keeps the original gate and takes the same
+    /// parameters, mapped inside its expression; e.g., the inverse of
+    /// $P(\theta)$ evaluates $P(-\theta)$. Only a raw `Gate::Expression`
+    /// falls back to the generic conjugate transpose of a [`DaggerGate`].
+    ///
+    /// Inverting twice gives a gate with the same unitary and parameters as
+    /// the original gate. For built-in gates, it is the original gate
+    /// itself; e.g., the inverse of X on a qutrit is $X^2$, whose inverse is
+    /// X again. An unphased displacement equal to X or Z inverts back to X
+    /// or Z.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qudit_gates::Gate;
+    /// assert_eq!(Gate::T().inverse(), Gate::Tdg());
+    /// assert_eq!(Gate::S().inverse().inverse(), Gate::S());
+    /// assert_eq!(Gate::P(3).inverse().inverse(), Gate::P(3));
+    /// assert_eq!(Gate::X(3).inverse().inverse(), Gate::X(3));
+    /// ```
+    pub fn inverse(&self) -> Gate {
+        match self {
+            Gate::HGate(gate) => {
+                if gate.radix == 2 {
+                    self.clone()
+                } else {
+                    Gate::Inverse(InverseGate::new(self.clone()))
+                }
+            },
+            Gate::PGate(_gate) => Gate::Inverse(InverseGate::new(self.clone())),
+            Gate::XGate(gate) => {
+                if gate.radix == 2 {
+                    self.clone()
+                } else {
+                    Gate::WeylGate(WeylGate::unphased(gate.radix, gate.radix - 1, 0))
+                }
+            },
+            Gate::U3Gate(_gate) => Gate::Inverse(InverseGate::new(self.clone())),
+            Gate::ZGate(gate) => {
+                if gate.radix == 2 {
+                    self.clone()
+                } else {
+                    Gate::WeylGate(WeylGate::unphased(gate.radix, 0, gate.radix - 1))
+                }
+            },
+            Gate::WeylGate(gate) => {
+                let radix = gate.radix;
+                let neg_a = (radix - gate.a) % radix;
+                let neg_b = (radix - gate.b) % radix;
+                if !gate.has_displacement_inverse() {
+                    Gate::Inverse(InverseGate::new(self.clone()))
+                } else if gate.phased {
+                    Gate::WeylGate(WeylGate::new(radix, neg_a, neg_b))
+                } else {
+                    // The shift and clock gates invert to unphased
+                    // displacements, which invert back to them.
+                    match (neg_a, neg_b) {
+                        (1, 0) => Gate::X(radix),
+                        (0, 1) => Gate::Z(radix),
+                        _ => Gate::WeylGate(WeylGate::unphased(radix, neg_a, neg_b)),
+                    }
+                }
+            },
+            Gate::CSumGate(gate) => Gate::CSumGate(CSumGate::new_general(
+                gate.control_radix,
+                gate.target_radix,
+                gate.target_radix - gate.multiplier,
+            )),
+            Gate::YGate(_gate) => self.clone(),
+            Gate::SGate(_gate) => Gate::SdgGate(SdgGate),
+            Gate::SdgGate(_gate) => Gate::SGate(SGate),
+            Gate::TGate(_gate) => Gate::TdgGate(TdgGate),
+            Gate::TdgGate(_gate) => Gate::TGate(TGate),
+            Gate::SXGate(_gate) => Gate::SXdgGate(SXdgGate),
+            Gate::SXdgGate(_gate) => Gate::SXGate(SXGate),
+            Gate::RXGate(_)
+            | Gate::RYGate(_)
+            | Gate::RZGate(_)
+            | Gate::RXXGate(_)
+            | Gate::RYYGate(_)
+            | Gate::RZZGate(_)
+            | Gate::RZXGate(_) => Gate::Inverse(InverseGate::new(self.clone())),
+            Gate::SwapGate(gate) => Gate::SwapGate(gate.inverse()),
+            Gate::PermutationGate(gate) => Gate::PermutationGate(gate.inverse()),
+            Gate::IGate(_gate) => self.clone(),
+            Gate::Controlled(gate) => Gate::Controlled(gate.inverse()),
+            Gate::Dagger(gate) => gate.gate().clone(),
+            Gate::Permuted(gate) => Gate::Permuted(gate.inverse()),
+            Gate::Embedded(gate) => Gate::Embedded(gate.inverse()),
+            Gate::Tensor(gate) => Gate::Tensor(gate.inverse()),
+            Gate::Product(gate) => Gate::Product(gate.inverse()),
+            Gate::Power(gate) => Gate::Power(gate.inverse()),
+            Gate::Fixed(gate) => Gate::Fixed(gate.inverse()),
+            Gate::Inverse(gate) => gate.gate().clone(),
+            Gate::Expression(expr) => Gate::Dagger(DaggerGate::from_gate(expr.clone())),
+        }
+    }
 }
 
 impl UnitaryExpressionGenerator for Gate {
@@ -80,7 +416,35 @@ impl UnitaryExpressionGenerator for Gate {
             Gate::PGate(gate) => gate.gen_expr(),
             Gate::XGate(gate) => gate.gen_expr(),
             Gate::U3Gate(gate) => gate.gen_expr(),
+            Gate::ZGate(gate) => gate.gen_expr(),
+            Gate::WeylGate(gate) => gate.gen_expr(),
+            Gate::CSumGate(gate) => gate.gen_expr(),
+            Gate::YGate(gate) => gate.gen_expr(),
+            Gate::SGate(gate) => gate.gen_expr(),
+            Gate::SdgGate(gate) => gate.gen_expr(),
+            Gate::TGate(gate) => gate.gen_expr(),
+            Gate::TdgGate(gate) => gate.gen_expr(),
+            Gate::SXGate(gate) => gate.gen_expr(),
+            Gate::SXdgGate(gate) => gate.gen_expr(),
+            Gate::RXGate(gate) => gate.gen_expr(),
+            Gate::RYGate(gate) => gate.gen_expr(),
+            Gate::RZGate(gate) => gate.gen_expr(),
+            Gate::RXXGate(gate) => gate.gen_expr(),
+            Gate::RYYGate(gate) => gate.gen_expr(),
+            Gate::RZZGate(gate) => gate.gen_expr(),
+            Gate::RZXGate(gate) => gate.gen_expr(),
+            Gate::SwapGate(gate) => gate.gen_expr(),
+            Gate::PermutationGate(gate) => gate.gen_expr(),
+            Gate::IGate(gate) => gate.gen_expr(),
             Gate::Controlled(gate) => gate.gen_expr(),
+            Gate::Dagger(gate) => gate.gen_expr(),
+            Gate::Permuted(gate) => gate.gen_expr(),
+            Gate::Embedded(gate) => gate.gen_expr(),
+            Gate::Tensor(gate) => gate.gen_expr(),
+            Gate::Product(gate) => gate.gen_expr(),
+            Gate::Power(gate) => gate.gen_expr(),
+            Gate::Fixed(gate) => gate.gen_expr(),
+            Gate::Inverse(gate) => gate.gen_expr(),
             Gate::Expression(expr) => expr.clone(),
         }
     }
@@ -89,508 +453,741 @@ impl UnitaryExpressionGenerator for Gate {
 impl HasParams for Gate {
     fn num_params(&self) -> usize {
         match self {
-            Gate::HGate(_gate) => 0,
-            Gate::XGate(_gate) => 0,
-            Gate::U3Gate(_gate) => 3,
-            Gate::PGate(gate) => gate.radix - 1,
+            Gate::HGate(gate) => gate.num_params(),
+            Gate::PGate(gate) => gate.num_params(),
+            Gate::XGate(gate) => gate.num_params(),
+            Gate::U3Gate(gate) => gate.num_params(),
+            Gate::ZGate(gate) => gate.num_params(),
+            Gate::WeylGate(gate) => gate.num_params(),
+            Gate::CSumGate(gate) => gate.num_params(),
+            Gate::YGate(gate) => gate.num_params(),
+            Gate::SGate(gate) => gate.num_params(),
+            Gate::SdgGate(gate) => gate.num_params(),
+            Gate::TGate(gate) => gate.num_params(),
+            Gate::TdgGate(gate) => gate.num_params(),
+            Gate::SXGate(gate) => gate.num_params(),
+            Gate::SXdgGate(gate) => gate.num_params(),
+            Gate::RXGate(gate) => gate.num_params(),
+            Gate::RYGate(gate) => gate.num_params(),
+            Gate::RZGate(gate) => gate.num_params(),
+            Gate::RXXGate(gate) => gate.num_params(),
+            Gate::RYYGate(gate) => gate.num_params(),
+            Gate::RZZGate(gate) => gate.num_params(),
+            Gate::RZXGate(gate) => gate.num_params(),
+            Gate::SwapGate(gate) => gate.num_params(),
+            Gate::PermutationGate(gate) => gate.num_params(),
+            Gate::IGate(gate) => gate.num_params(),
             Gate::Controlled(gate) => gate.num_params(),
+            Gate::Dagger(gate) => gate.num_params(),
+            Gate::Permuted(gate) => gate.num_params(),
+            Gate::Embedded(gate) => gate.num_params(),
+            Gate::Tensor(gate) => gate.num_params(),
+            Gate::Product(gate) => gate.num_params(),
+            Gate::Power(gate) => gate.num_params(),
+            Gate::Fixed(gate) => gate.num_params(),
+            Gate::Inverse(gate) => gate.num_params(),
             Gate::Expression(expr) => expr.num_params(),
         }
     }
 }
 
+impl QuditSystem for Gate {
+    fn radices(&self) -> QuditRadices {
+        match self {
+            Gate::HGate(gate) => gate.radices(),
+            Gate::PGate(gate) => gate.radices(),
+            Gate::XGate(gate) => gate.radices(),
+            Gate::U3Gate(gate) => gate.radices(),
+            Gate::ZGate(gate) => gate.radices(),
+            Gate::WeylGate(gate) => gate.radices(),
+            Gate::CSumGate(gate) => gate.radices(),
+            Gate::YGate(gate) => gate.radices(),
+            Gate::SGate(gate) => gate.radices(),
+            Gate::SdgGate(gate) => gate.radices(),
+            Gate::TGate(gate) => gate.radices(),
+            Gate::TdgGate(gate) => gate.radices(),
+            Gate::SXGate(gate) => gate.radices(),
+            Gate::SXdgGate(gate) => gate.radices(),
+            Gate::RXGate(gate) => gate.radices(),
+            Gate::RYGate(gate) => gate.radices(),
+            Gate::RZGate(gate) => gate.radices(),
+            Gate::RXXGate(gate) => gate.radices(),
+            Gate::RYYGate(gate) => gate.radices(),
+            Gate::RZZGate(gate) => gate.radices(),
+            Gate::RZXGate(gate) => gate.radices(),
+            Gate::SwapGate(gate) => gate.radices(),
+            Gate::PermutationGate(gate) => gate.radices(),
+            Gate::IGate(gate) => gate.radices(),
+            Gate::Controlled(gate) => gate.radices(),
+            Gate::Dagger(gate) => gate.radices(),
+            Gate::Permuted(gate) => gate.radices(),
+            Gate::Embedded(gate) => gate.radices(),
+            Gate::Tensor(gate) => gate.radices(),
+            Gate::Product(gate) => gate.radices(),
+            Gate::Power(gate) => gate.radices(),
+            Gate::Fixed(gate) => gate.radices(),
+            Gate::Inverse(gate) => gate.radices(),
+            Gate::Expression(expr) => expr.radices(),
+        }
+    }
+
+    fn num_qudits(&self) -> usize {
+        match self {
+            Gate::HGate(gate) => gate.num_qudits(),
+            Gate::PGate(gate) => gate.num_qudits(),
+            Gate::XGate(gate) => gate.num_qudits(),
+            Gate::U3Gate(gate) => gate.num_qudits(),
+            Gate::ZGate(gate) => gate.num_qudits(),
+            Gate::WeylGate(gate) => gate.num_qudits(),
+            Gate::CSumGate(gate) => gate.num_qudits(),
+            Gate::YGate(gate) => gate.num_qudits(),
+            Gate::SGate(gate) => gate.num_qudits(),
+            Gate::SdgGate(gate) => gate.num_qudits(),
+            Gate::TGate(gate) => gate.num_qudits(),
+            Gate::TdgGate(gate) => gate.num_qudits(),
+            Gate::SXGate(gate) => gate.num_qudits(),
+            Gate::SXdgGate(gate) => gate.num_qudits(),
+            Gate::RXGate(gate) => gate.num_qudits(),
+            Gate::RYGate(gate) => gate.num_qudits(),
+            Gate::RZGate(gate) => gate.num_qudits(),
+            Gate::RXXGate(gate) => gate.num_qudits(),
+            Gate::RYYGate(gate) => gate.num_qudits(),
+            Gate::RZZGate(gate) => gate.num_qudits(),
+            Gate::RZXGate(gate) => gate.num_qudits(),
+            Gate::SwapGate(gate) => gate.num_qudits(),
+            Gate::PermutationGate(gate) => gate.num_qudits(),
+            Gate::IGate(gate) => gate.num_qudits(),
+            Gate::Controlled(gate) => gate.num_qudits(),
+            Gate::Dagger(gate) => gate.num_qudits(),
+            Gate::Permuted(gate) => gate.num_qudits(),
+            Gate::Embedded(gate) => gate.num_qudits(),
+            Gate::Tensor(gate) => gate.num_qudits(),
+            Gate::Product(gate) => gate.num_qudits(),
+            Gate::Power(gate) => gate.num_qudits(),
+            Gate::Fixed(gate) => gate.num_qudits(),
+            Gate::Inverse(gate) => gate.num_qudits(),
+            Gate::Expression(expr) => expr.num_qudits(),
+        }
+    }
+
+    fn dimension(&self) -> usize {
+        match self {
+            Gate::HGate(gate) => gate.dimension(),
+            Gate::PGate(gate) => gate.dimension(),
+            Gate::XGate(gate) => gate.dimension(),
+            Gate::U3Gate(gate) => gate.dimension(),
+            Gate::ZGate(gate) => gate.dimension(),
+            Gate::WeylGate(gate) => gate.dimension(),
+            Gate::CSumGate(gate) => gate.dimension(),
+            Gate::YGate(gate) => gate.dimension(),
+            Gate::SGate(gate) => gate.dimension(),
+            Gate::SdgGate(gate) => gate.dimension(),
+            Gate::TGate(gate) => gate.dimension(),
+            Gate::TdgGate(gate) => gate.dimension(),
+            Gate::SXGate(gate) => gate.dimension(),
+            Gate::SXdgGate(gate) => gate.dimension(),
+            Gate::RXGate(gate) => gate.dimension(),
+            Gate::RYGate(gate) => gate.dimension(),
+            Gate::RZGate(gate) => gate.dimension(),
+            Gate::RXXGate(gate) => gate.dimension(),
+            Gate::RYYGate(gate) => gate.dimension(),
+            Gate::RZZGate(gate) => gate.dimension(),
+            Gate::RZXGate(gate) => gate.dimension(),
+            Gate::SwapGate(gate) => gate.dimension(),
+            Gate::PermutationGate(gate) => gate.dimension(),
+            Gate::IGate(gate) => gate.dimension(),
+            Gate::Controlled(gate) => gate.dimension(),
+            Gate::Dagger(gate) => gate.dimension(),
+            Gate::Permuted(gate) => gate.dimension(),
+            Gate::Embedded(gate) => gate.dimension(),
+            Gate::Tensor(gate) => gate.dimension(),
+            Gate::Product(gate) => gate.dimension(),
+            Gate::Power(gate) => gate.dimension(),
+            Gate::Fixed(gate) => gate.dimension(),
+            Gate::Inverse(gate) => gate.dimension(),
+            Gate::Expression(expr) => expr.dimension(),
+        }
+    }
+}
+
+impl QuditGate for Gate {
+    fn name(&self) -> String {
+        match self {
+            Gate::HGate(gate) => gate.name(),
+            Gate::PGate(gate) => gate.name(),
+            Gate::XGate(gate) => gate.name(),
+            Gate::U3Gate(gate) => gate.name(),
+            Gate::ZGate(gate) => gate.name(),
+            Gate::WeylGate(gate) => gate.name(),
+            Gate::CSumGate(gate) => gate.name(),
+            Gate::YGate(gate) => gate.name(),
+            Gate::SGate(gate) => gate.name(),
+            Gate::SdgGate(gate) => gate.name(),
+            Gate::TGate(gate) => gate.name(),
+            Gate::TdgGate(gate) => gate.name(),
+            Gate::SXGate(gate) => gate.name(),
+            Gate::SXdgGate(gate) => gate.name(),
+            Gate::RXGate(gate) => gate.name(),
+            Gate::RYGate(gate) => gate.name(),
+            Gate::RZGate(gate) => gate.name(),
+            Gate::RXXGate(gate) => gate.name(),
+            Gate::RYYGate(gate) => gate.name(),
+            Gate::RZZGate(gate) => gate.name(),
+            Gate::RZXGate(gate) => gate.name(),
+            Gate::SwapGate(gate) => gate.name(),
+            Gate::PermutationGate(gate) => gate.name(),
+            Gate::IGate(gate) => gate.name(),
+            Gate::Controlled(gate) => gate.name(),
+            Gate::Dagger(gate) => gate.name(),
+            Gate::Permuted(gate) => gate.name(),
+            Gate::Embedded(gate) => gate.name(),
+            Gate::Tensor(gate) => gate.name(),
+            Gate::Product(gate) => gate.name(),
+            Gate::Power(gate) => gate.name(),
+            Gate::Fixed(gate) => gate.name(),
+            Gate::Inverse(gate) => gate.name(),
+            Gate::Expression(expr) => expr.name().to_string(),
+        }
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        match self {
+            Gate::HGate(gate) => gate.param_names(),
+            Gate::PGate(gate) => gate.param_names(),
+            Gate::XGate(gate) => gate.param_names(),
+            Gate::U3Gate(gate) => gate.param_names(),
+            Gate::ZGate(gate) => gate.param_names(),
+            Gate::WeylGate(gate) => gate.param_names(),
+            Gate::CSumGate(gate) => gate.param_names(),
+            Gate::YGate(gate) => gate.param_names(),
+            Gate::SGate(gate) => gate.param_names(),
+            Gate::SdgGate(gate) => gate.param_names(),
+            Gate::TGate(gate) => gate.param_names(),
+            Gate::TdgGate(gate) => gate.param_names(),
+            Gate::SXGate(gate) => gate.param_names(),
+            Gate::SXdgGate(gate) => gate.param_names(),
+            Gate::RXGate(gate) => gate.param_names(),
+            Gate::RYGate(gate) => gate.param_names(),
+            Gate::RZGate(gate) => gate.param_names(),
+            Gate::RXXGate(gate) => gate.param_names(),
+            Gate::RYYGate(gate) => gate.param_names(),
+            Gate::RZZGate(gate) => gate.param_names(),
+            Gate::RZXGate(gate) => gate.param_names(),
+            Gate::SwapGate(gate) => gate.param_names(),
+            Gate::PermutationGate(gate) => gate.param_names(),
+            Gate::IGate(gate) => gate.param_names(),
+            Gate::Controlled(gate) => gate.param_names(),
+            Gate::Dagger(gate) => gate.param_names(),
+            Gate::Permuted(gate) => gate.param_names(),
+            Gate::Embedded(gate) => gate.param_names(),
+            Gate::Tensor(gate) => gate.param_names(),
+            Gate::Product(gate) => gate.param_names(),
+            Gate::Power(gate) => gate.param_names(),
+            Gate::Fixed(gate) => gate.param_names(),
+            Gate::Inverse(gate) => gate.param_names(),
+            Gate::Expression(expr) => {
+                (0..expr.num_params()).map(|i| format!("θ{}", i)).collect()
+            },
+        }
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        match self {
+            Gate::HGate(gate) => gate.param_specs(),
+            Gate::PGate(gate) => gate.param_specs(),
+            Gate::XGate(gate) => gate.param_specs(),
+            Gate::U3Gate(gate) => gate.param_specs(),
+            Gate::ZGate(gate) => gate.param_specs(),
+            Gate::WeylGate(gate) => gate.param_specs(),
+            Gate::CSumGate(gate) => gate.param_specs(),
+            Gate::YGate(gate) => gate.param_specs(),
+            Gate::SGate(gate) => gate.param_specs(),
+            Gate::SdgGate(gate) => gate.param_specs(),
+            Gate::TGate(gate) => gate.param_specs(),
+            Gate::TdgGate(gate) => gate.param_specs(),
+            Gate::SXGate(gate) => gate.param_specs(),
+            Gate::SXdgGate(gate) => gate.param_specs(),
+            Gate::RXGate(gate) => gate.param_specs(),
+            Gate::RYGate(gate) => gate.param_specs(),
+            Gate::RZGate(gate) => gate.param_specs(),
+            Gate::RXXGate(gate) => gate.param_specs(),
+            Gate::RYYGate(gate) => gate.param_specs(),
+            Gate::RZZGate(gate) => gate.param_specs(),
+            Gate::RZXGate(gate) => gate.param_specs(),
+            Gate::SwapGate(gate) => gate.param_specs(),
+            Gate::PermutationGate(gate) => gate.param_specs(),
+            Gate::IGate(gate) => gate.param_specs(),
+            Gate::Controlled(gate) => gate.param_specs(),
+            Gate::Dagger(gate) => gate.param_specs(),
+            Gate::Permuted(gate) => gate.param_specs(),
+            Gate::Embedded(gate) => gate.param_specs(),
+            Gate::Tensor(gate) => gate.param_specs(),
+            Gate::Product(gate) => gate.param_specs(),
+            Gate::Power(gate) => gate.param_specs(),
+            Gate::Fixed(gate) => gate.param_specs(),
+            Gate::Inverse(gate) => gate.param_specs(),
+            Gate::Expression(expr) => {
+                (0..expr.num_params())
+                    .map(|i| ParamSpec::angle(format!("θ{}", i)))
+                    .collect()
+            },
+        }
+    }
+}
+
+impl From<HGate> for Gate {
+    fn from(gate: HGate) -> Self {
+        Gate::HGate(gate)
+    }
+}
+
+impl From<PGate> for Gate {
+    fn from(gate: PGate) -> Self {
+        Gate::PGate(gate)
+    }
+}
+
+impl From<XGate> for Gate {
+    fn from(gate: XGate) -> Self {
+        Gate::XGate(gate)
+    }
+}
+
+impl From<U3Gate> for Gate {
+    fn from(gate: U3Gate) -> Self {
+        Gate::U3Gate(gate)
+    }
+}
+
+impl From<ZGate> for Gate {
+    fn from(gate: ZGate) -> Self {
+        Gate::ZGate(gate)
+    }
+}
+
+impl From<WeylGate> for Gate {
+    fn from(gate: WeylGate) -> Self {
+        Gate::WeylGate(gate)
+    }
+}
+
+impl From<CSumGate> for Gate {
+    fn from(gate: CSumGate) -> Self {
+        Gate::CSumGate(gate)
+    }
+}
+
+impl From<YGate> for Gate {
+    fn from(gate: YGate) -> Self {
+        Gate::YGate(gate)
+    }
+}
+
+impl From<SGate> for Gate {
+    fn from(gate: SGate) -> Self {
+        Gate::SGate(gate)
+    }
+}
+
+impl From<SdgGate> for Gate {
+    fn from(gate: SdgGate) -> Self {
+        Gate::SdgGate(gate)
+    }
+}
+
+impl From<TGate> for Gate {
+    fn from(gate: TGate) -> Self {
+        Gate::TGate(gate)
+    }
+}
+
+impl From<TdgGate> for Gate {
+    fn from(gate: TdgGate) -> Self {
+        Gate::TdgGate(gate)
+    }
+}
+
+impl From<SXGate> for Gate {
+    fn from(gate: SXGate) -> Self {
+        Gate::SXGate(gate)
+    }
+}
+
+impl From<SXdgGate> for Gate {
+    fn from(gate: SXdgGate) -> Self {
+        Gate::SXdgGate(gate)
+    }
+}
+
+impl From<RXGate> for Gate {
+    fn from(gate: RXGate) -> Self {
+        Gate::RXGate(gate)
+    }
+}
+
+impl From<RYGate> for Gate {
+    fn from(gate: RYGate) -> Self {
+        Gate::RYGate(gate)
+    }
+}
+
+impl From<RZGate> for Gate {
+    fn from(gate: RZGate) -> Self {
+        Gate::RZGate(gate)
+    }
+}
+
+impl From<RXXGate> for Gate {
+    fn from(gate: RXXGate) -> Self {
+        Gate::RXXGate(gate)
+    }
+}
+
+impl From<RYYGate> for Gate {
+    fn from(gate: RYYGate) -> Self {
+        Gate::RYYGate(gate)
+    }
+}
+
+impl From<RZZGate> for Gate {
+    fn from(gate: RZZGate) -> Self {
+        Gate::RZZGate(gate)
+    }
+}
+
+impl From<RZXGate> for Gate {
+    fn from(gate: RZXGate) -> Self {
+        Gate::RZXGate(gate)
+    }
+}
+
+impl From<SwapGate> for Gate {
+    fn from(gate: SwapGate) -> Self {
+        Gate::SwapGate(gate)
+    }
+}
+
+impl From<PermutationGate> for Gate {
+    fn from(gate: PermutationGate) -> Self {
+        Gate::PermutationGate(gate)
+    }
+}
+
+impl From<IGate> for Gate {
+    fn from(gate: IGate) -> Self {
+        Gate::IGate(gate)
+    }
+}
+
+impl From<ControlledGate> for Gate {
+    fn from(gate: ControlledGate) -> Self {
+        Gate::Controlled(gate)
+    }
+}
+
+impl From<DaggerGate> for Gate {
+    fn from(gate: DaggerGate) -> Self {
+        Gate::Dagger(gate)
+    }
+}
+
+impl From<PermutedGate> for Gate {
+    fn from(gate: PermutedGate) -> Self {
+        Gate::Permuted(gate)
+    }
+}
+
+impl From<EmbeddedGate> for Gate {
+    fn from(gate: EmbeddedGate) -> Self {
+        Gate::Embedded(gate)
+    }
+}
+
+impl From<TensorGate> for Gate {
+    fn from(gate: TensorGate) -> Self {
+        Gate::Tensor(gate)
+    }
+}
+
+impl From<ProductGate> for Gate {
+    fn from(gate: ProductGate) -> Self {
+        Gate::Product(gate)
+    }
+}
+
+impl From<PowerGate> for Gate {
+    fn from(gate: PowerGate) -> Self {
+        Gate::Power(gate)
+    }
+}
+
+impl From<FixedParamGate> for Gate {
+    fn from(gate: FixedParamGate) -> Self {
+        Gate::Fixed(gate)
+    }
+}
+
+impl From<InverseGate> for Gate {
+    fn from(gate: InverseGate) -> Self {
+        Gate::Inverse(gate)
+    }
+}
+
+impl From<UnitaryExpression> for Gate {
+    fn from(expr: UnitaryExpression) -> Self {
+        Gate::Expression(expr)
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use qudit_core::matrix::MatVec;
-    use qudit_core::unitary::{UnitaryFn, DifferentiableUnitaryFn, UnitaryMatrix};
+    use std::collections::HashMap;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
     use qudit_core::c64;
-    //use qudit_expr::{DifferentiationLevel, Module, ModuleBuilder};
+    use crate::testing::{assert_gradient_matches_finite_difference, assert_is_unitary};
+
+    /// Every gate constructible for a qudit with `radix` levels.
+    fn all_gates_with_radix(radix: usize) -> Vec<Gate> {
+        let mut gates = vec![
+            Gate::I(radix),
+            Gate::H(radix),
+            Gate::X(radix),
+            Gate::Z(radix),
+            Gate::P(radix),
+            Gate::Swap(radix),
+            Gate::SwapGate(SwapGate::new_mixed(radix, 2)),
+            Gate::SwapGate(SwapGate::new_mixed(2, radix)),
+            Gate::Permutation(radices![radix, 2, 3], vec![2, 0, 1]),
+            Gate::CSUM(radix),
+            Gate::CSumGate(CSumGate::new_mixed(radix, 2)),
+            Gate::CSumGate(CSumGate::new_mixed(2, radix)),
+            Gate::CSumGate(CSumGate::new_general(radix, radix, radix - 1)),
+            Gate::RX(radix, (0, radix - 1)),
+            Gate::RY(radix, (radix - 1, 0)),
+            Gate::RZ(radix, (0, 1)),
+            Gate::RZZ(radix),
+            Gate::Controlled(ControlledGate::from_gate(
+                XGate::new(radix),
+                radices![radix],
+                vec![vec![radix - 1]],
+            )),
+            Gate::Controlled(ControlledGate::from_gate(
+                PGate::new(radix),
+                radices![2, radix],
+                vec![vec![1], (1..radix).collect()],
+            )),
+            Gate::Dagger(DaggerGate::from_gate(PGate::new(radix))),
+            Gate::Permuted(PermutedGate::new(CSumGate::new_mixed(radix, 2), vec![1, 0])),
+            Gate::Permuted(PermutedGate::new(
+                Gate::Permutation(radices![radix, radix, radix], vec![1, 2, 0]),
+                vec![2, 0, 1],
+            )),
+            Gate::Embedded(EmbeddedGate::new(
+                XGate::new(2),
+                radices![radix + 1],
+                vec![vec![radix, 0]],
+            )),
+            Gate::Embedded(EmbeddedGate::new(
+                Gate::CSUM(2),
+                radices![radix, 3],
+                vec![vec![0, radix - 1], vec![2, 1]],
+            )),
+            Gate::Tensor(TensorGate::new(vec![Gate::H(radix), Gate::P(2), Gate::CSUM(radix)])),
+            Gate::Product(ProductGate::new(radices![radix, 2, radix], vec![
+                (Gate::P(radix), vec![2]),
+                (Gate::CSUM(radix), vec![2, 0]),
+                (Gate::CSumGate(CSumGate::new_mixed(radix, 2)), vec![0, 1]),
+            ])),
+            Gate::Power(PowerGate::new(Gate::X(radix), 3.0)),
+            Gate::Power(PowerGate::new(Gate::H(radix), -2.0)),
+            Gate::Power(PowerGate::new(Gate::Swap(radix), 0.5)),
+            Gate::Power(PowerGate::new(Gate::X(radix), 1.0 / 3.0)),
+            Gate::Fixed(FixedParamGate::new(Gate::P(radix), HashMap::from([(0, 0.7)]))),
+        ];
+        for a in 0..radix {
+            for b in 0..radix {
+                gates.push(Gate::Weyl(radix, a, b));
+                gates.push(Gate::WeylGate(WeylGate::unphased(radix, a, b)));
+            }
+        }
+        if radix == 2 {
+            gates.extend([
+                Gate::Y(),
+                Gate::S(),
+                Gate::Sdg(),
+                Gate::T(),
+                Gate::Tdg(),
+                Gate::SX(),
+                Gate::SXdg(),
+                Gate::U3(),
+                Gate::RXX(),
+                Gate::RYY(),
+                Gate::RZX(),
+                Gate::CX(),
+                Gate::CP(),
+            ]);
+        }
+        let inverses: Vec<Gate> = gates.iter().map(|g| g.inverse()).collect();
+        gates.extend(inverses);
+        gates
+    }
 
     #[test]
-    fn test_h_gate() {
-        let h_gate = HGate::new(2);
-        let expr = h_gate.gen_expr();
-        println!("{:?}", expr);
-
-        let params = vec![];
-        let utry: UnitaryMatrix<c64> = expr.get_unitary(&params);
-        println!("{:?}", utry);
-        // let mut mat: qudit_core::matrix::Mat<c64> = qudit_core::matrix::Mat::zeros(2, 2);
-        // unsafe {
-        //     let ptr = qudit_core::matrix::matmut_to_ptr(mat.as_mut());
-        //     *ptr = 2.3f64;
-        // }
-        // println!("{:?}", mat);
+    fn test_all_gates_are_unitary() {
+        let mut rng = rand::thread_rng();
+        for radix in 2..=7 {
+            for gate in all_gates_with_radix(radix) {
+                let expr = gate.gen_expr();
+                assert_eq!(gate.radices(), expr.radices(), "{:?}", gate);
+                assert_eq!(gate.dimension(), expr.dimension(), "{:?}", gate);
+                assert_eq!(gate.num_params(), expr.num_params(), "{:?}", gate);
+
+                let params = gate.random_params(&mut rng);
+                let utry: UnitaryMatrix<c64> = expr.get_unitary(&params);
+                assert_eq!(utry.dimension(), gate.dimension(), "{:?}", gate);
+                assert_is_unitary(&utry, 1e-8);
+            }
+        }
     }
 
     #[test]
-    fn test_p_gate() {
-        let p_gate = PGate::new(2);
-        let expr = p_gate.gen_expr();
-        println!("{:?}", expr);
-
-        let params = vec![1.7];
-        let utry: UnitaryMatrix<c64> = expr.get_unitary(&params);
-        println!("{:?}", utry);
-
-        let grad: MatVec<c64> = expr.get_gradient(&params);
-        println!("{:?}", grad);
+    fn test_parameterized_gradients() {
+        let mut rng = rand::thread_rng();
+        let mut gates = vec![Gate::U3(), Gate::CP()];
+        for radix in 2..=5 {
+            gates.push(Gate::P(radix));
+            gates.push(Gate::Controlled(ControlledGate::from_gate(
+                PGate::new(radix),
+                radices![3],
+                vec![vec![0, 2]],
+            )));
+            gates.push(Gate::Dagger(DaggerGate::from_gate(PGate::new(radix))));
+        }
+        gates.push(Gate::Controlled(ControlledGate::from_gate(
+            U3Gate,
+            radices![2, 3],
+            vec![vec![1], vec![2]],
+        )));
+        gates.push(Gate::Dagger(DaggerGate::from_gate(U3Gate)));
+        gates.push(Gate::Permuted(PermutedGate::new(
+            ControlledGate::from_gate(U3Gate, radices![3], vec![vec![2]]),
+            vec![1, 0],
+        )));
+        gates.push(Gate::Embedded(EmbeddedGate::new(
+            Gate::CP(),
+            radices![3, 4],
+            vec![vec![2, 0], vec![1, 3]],
+        )));
+        gates.push(Gate::Tensor(TensorGate::new(vec![Gate::U3(), Gate::P(3), Gate::U3()])));
+        gates.push(Gate::Product(ProductGate::new(radices![2, 3], vec![
+            (Gate::U3(), vec![0]),
+            (Gate::P(3), vec![1]),
+            (
+                Gate::Controlled(ControlledGate::from_gate(
+                    PGate::new(3),
+                    radices![2],
+                    vec![vec![1]],
+                )),
+                vec![0, 1],
+            ),
+        ])));
+        gates.push(Gate::Dagger(DaggerGate::from_gate(ControlledGate::from_gate(
+            U3Gate,
+            radices![2],
+            vec![vec![0]],
+        ))));
+        gates.push(Gate::Fixed(FixedParamGate::new(Gate::U3(), HashMap::from([(0, 0.4)]))));
+        gates.push(Gate::Fixed(FixedParamGate::new(Gate::P(4), HashMap::from([(2, 1.1)]))));
+
+        for gate in gates {
+            let params = gate.random_params(&mut rng);
+            assert_gradient_matches_finite_difference(&gate, &params, 1e-5);
+        }
     }
 
     #[test]
-    fn test_u3_gate() {
-        let u3_gate = U3Gate;
-        let expr = u3_gate.gen_expr();
-        println!("{:?}", expr);
-
-        let params = vec![1.7, 2.3, 3.1];
-        let utry: UnitaryMatrix<c64> = expr.get_unitary(&params);
-        println!("{:?}", utry);
+    fn test_composed_gates_accept_expression_generators() {
+        let dagger = DaggerGate::new(PGate::new(3));
+        assert!(matches!(dagger.gate(), Gate::Expression(_)));
+        let expected = Gate::from(DaggerGate::from_gate(PGate::new(3)));
+        assert!(Gate::from(dagger).is_equivalent(&expected, 1e-8));
+
+        let cx = ControlledGate::new(XGate::new(2), radices![2], vec![vec![1]]);
+        assert!(matches!(cx.gate(), Gate::Expression(_)));
+        assert!(Gate::from(cx).is_equivalent(&Gate::CX(), 1e-8));
+    }
 
-        let grad: MatVec<c64> = expr.get_gradient(&params);
-        println!("{:?}", grad);
+    #[test]
+    fn test_weyl_inverses_are_exact() {
+        for radix in 2..=5 {
+            for gate in WeylGate::all(radix) {
+                let u: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&[]);
+                let inverse = Gate::from(gate.clone()).inverse();
+                let v: UnitaryMatrix<c64> = inverse.gen_expr().get_unitary(&[]);
+                for i in 0..radix {
+                    for j in 0..radix {
+                        let mut entry = c64::new(0.0, 0.0);
+                        for k in 0..radix {
+                            entry += u[(i, k)] * v[(k, j)];
+                        }
+                        let expected = c64::new(if i == j { 1.0 } else { 0.0 }, 0.0);
+                        assert!((entry - expected).norm() < 1e-10, "{:?}", gate);
+                    }
+                }
+            }
+        }
     }
 
-    // #[test]
-    // fn test_time_u3() {
-    //     let u3_gate = U3Gate;
-    //     let expr = u3_gate.gen_expr();
+    #[test]
+    fn test_inverse_round_trips() {
+        let mut rng = rand::thread_rng();
+        for radix in 2..=5 {
+            for gate in all_gates_with_radix(radix) {
+                let round_trip = gate.inverse().inverse();
+                assert_eq!(round_trip.radices(), gate.radices(), "{:?}", gate);
+                assert_eq!(round_trip.num_params(), gate.num_params(), "{:?}", gate);
+
+                let params = gate.random_params(&mut rng);
+                let utry: UnitaryMatrix<c64> = gate.gen_expr().get_unitary(&params);
+                let back: UnitaryMatrix<c64> = round_trip.gen_expr().get_unitary(&params);
+                crate::testing::assert_unitaries_close(&utry, &back, 1e-8);
+            }
+            assert_eq!(Gate::X(radix).inverse().inverse(), Gate::X(radix));
+            assert_eq!(Gate::Z(radix).inverse().inverse(), Gate::Z(radix));
+        }
+    }
 
-    //     let mut out_utry: Mat<c64> = Mat::zeros(2, 2);
-    //     let mut out_grad: MatVec<c64> = MatVec::zeros(2, 2, 3);
+    #[test]
+    fn test_h_gate() {
+        let utry: UnitaryMatrix<c64> = HGate::new(2).gen_expr().get_unitary(&[]);
+        let s = 1.0 / 2.0_f64.sqrt();
+        crate::assert_unitary_close!(utry, [
+            [s, s],
+            [s, -s],
+        ]);
+    }
 
-    //     let name = expr.name();
-    //     let module: Module<c64> = ModuleBuilder::new("test", DifferentiationLevel::Gradient)
-    //         .add_expression_with_stride(expr, out_utry.col_stride().try_into().unwrap())
-    //         .build();
+    #[test]
+    fn test_p_gate() {
+        let theta = 1.7;
+        let utry: UnitaryMatrix<c64> = PGate::new(2).gen_expr().get_unitary(&[theta]);
+        crate::assert_unitary_close!(utry, [
+            [c64::new(1.0, 0.0), c64::new(0.0, 0.0)],
+            [c64::new(0.0, 0.0), c64::new(theta.cos(), theta.sin())],
+        ]);
+    }
 
-    //     let utry_and_grad_func = module.get_function_and_gradient(&name).unwrap();
-    //     let out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(out_utry.as_mut()) };
-    //     let out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(out_grad.as_mut()) };
-    
-    //     let n = 1000000;
-    //     let params = vec![vec![rand::random::<f64>(), rand::random::<f64>(), rand::random::<f64>()]; n];
-    //     let start = std::time::Instant::now();
-    //     for i in 0..n {
-    //         unsafe { utry_and_grad_func.call(params[i].as_ptr(), out_ptr, out_grad_ptr); }
-    //     }
-    //     let duration = start.elapsed();
-    //     println!("U3, Time elapsed: {:?}", duration);
-    //     println!("U3, Time per call: {:?}", duration / n as u32);
-
-    //     let u3_gate = U3GateNative;
-    //     let start = std::time::Instant::now();
-    //     for i in 0..n {
-    //         u3_gate.write_unitary_and_gradient(&params[i], out_utry.as_mut(), out_grad.as_mut());
-    //     }
-    //     let duration = start.elapsed();
-    //     println!("Native, Time elapsed: {:?}", duration);
-    //     println!("Native, Time per call: {:?}", duration / n as u32);
-    // }
-
-    // #[test]
-    // fn test_time_fusion() {
-    //     let u3_gate = U3Gate;
-    //     let cnot_gate = CNOTGate;
-    //     let u3_expr = u3_gate.gen_expr();
-    //     let cnot_expr = cnot_gate.gen_expr();
-    //     let u3u3 = u3_expr.otimes(&u3_expr);
-    //     let cnotu3u3 = u3u3.dot(&cnot_expr);
-
-    //     let mut out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-
-    //     let name = cnotu3u3.name();
-    //     let module: Module<c64> = ModuleBuilder::new("test", DifferentiationLevel::Gradient)
-    //         .add_expression_with_stride(cnotu3u3, out_utry.col_stride().try_into().unwrap())
-    //         .build();
-    //     // println!("{}", module);
-
-    //     let utry_and_grad_func = module.get_function_and_gradient(&name).unwrap();
-    //     let out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(out_utry.as_mut()) };
-    //     let out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(out_grad.as_mut()) };
-    
-    //     let n = 1000000;
-    //     let params = vec![vec![rand::random::<f64>(); 6]; n];
-    //     let start = std::time::Instant::now();
-    //     for i in 0..n {
-    //         unsafe { utry_and_grad_func.call(params[i].as_ptr(), out_ptr, out_grad_ptr); }
-    //     }
-    //     let duration = start.elapsed();
-    //     println!("Time elapsed: {:?}", duration);
-    //     println!("Time per call: {:?}", duration / n as u32);
-
-
-    //     let u3_gate = U3GateNative;
-    //     let cnot_gate = CNOTGateNative;
-
-    //     let mut u31_out_utry: Mat<c64> = Mat::zeros(2, 2);
-    //     let mut u31_out_grad: MatVec<c64> = MatVec::zeros(2, 2, 3);
-    //     let mut u32_out_utry: Mat<c64> = Mat::zeros(2, 2);
-    //     let mut u32_out_grad: MatVec<c64> = MatVec::zeros(2, 2, 3);
-    //     let mut cnot_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut cnot_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 0);
-    //     let mut kron_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut kron_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-    //     let mut mul_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-
-
-    //     let start = std::time::Instant::now();
-    //     for i in 0..n {
-    //         u3_gate.write_unitary_and_gradient(&params[i][0..3], u31_out_utry.as_mut(), u31_out_grad.as_mut());
-    //         u3_gate.write_unitary_and_gradient(&params[i][3..6], u32_out_utry.as_mut(), u32_out_grad.as_mut());
-    //         cnot_gate.write_unitary_and_gradient(&[], cnot_out_utry.as_mut(), cnot_out_grad.as_mut());
-            
-    //         unsafe {
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_utry.as_mut(), u31_out_utry.as_ref(), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(0), u31_out_grad.mat_ref(0), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(1), u31_out_grad.mat_ref(1), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(2), u31_out_grad.mat_ref(2), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(3), u31_out_utry.as_ref(), u32_out_grad.mat_ref(0));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(4), u31_out_utry.as_ref(), u32_out_grad.mat_ref(1));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(5), u31_out_utry.as_ref(), u32_out_grad.mat_ref(2));
-
-    //             qudit_core::accel::matmul_unchecked(kron_out_utry.as_ref(), cnot_out_utry.as_ref(), mul_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(0), cnot_out_utry.as_ref(), mul_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(1), cnot_out_utry.as_ref(), mul_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(2), cnot_out_utry.as_ref(), mul_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(3), cnot_out_utry.as_ref(), mul_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(4), cnot_out_utry.as_ref(), mul_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(5), cnot_out_utry.as_ref(), mul_out_grad.mat_mut(5));
-    //         }
-    //     }
-    //     let duration = start.elapsed();
-    //     println!("Native, Time elapsed: {:?}", duration);
-    //     println!("Native, Time per call: {:?}", duration / n as u32);
-    // }
-
-    // #[test]
-    // fn test_time_fusion3() {
-    //     let u3_gate = U3Gate;
-    //     let cnot_gate = CNOTGate;
-    //     let u3_expr = u3_gate.gen_expr();
-    //     let cnot_expr = cnot_gate.gen_expr();
-    //     let u3u3 = u3_expr.otimes(&u3_expr);
-    //     let cnotu3u3 = u3u3.dot(&cnot_expr);
-    //     // let full_expr = cnotu3u3.dot(&cnotu3u3).dot(&cnotu3u3).dot(&u3u3);
-
-    //     let mut out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut out_grad: MatVec<c64> = MatVec::zeros(4, 4, 24);
-    //     // let params = vec![1.7, 2.3, 3.1];
-
-    //     // let name = full_expr.name();
-    //     // let module: Module<c64> = ModuleBuilder::new("test", DifferentiationLevel::Gradient)
-    //     //     .add_expression_with_stride(full_expr, out_utry.col_stride().try_into().unwrap())
-    //     //     .build();
-    //     // println!("{}", module);
-
-    //     // let utry_and_grad_func = module.get_function_and_gradient(&name).unwrap();
-    //     // let out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(out_utry.as_mut()) };
-    //     // let out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(out_grad.as_mut()) };
-    
-    //     let n = 1000000;
-    //     let params = vec![vec![rand::random::<f64>(); 24]; n];
-    //     // let start = std::time::Instant::now();
-    //     // for i in 0..n {
-    //     //     unsafe { utry_and_grad_func.call(params[i].as_ptr(), out_ptr, out_grad_ptr); }
-    //     // }
-    //     // let duration = start.elapsed();
-    //     // println!("Time elapsed: {:?}", duration);
-    //     // println!("Time per call: {:?}", duration / n as u32);
-
-    //     let mut kron_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut kron_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-    //     let mut mul_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-    //     let mut mul2_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul2_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-    //     let mut mul3_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul3_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-    //     let mut mul4_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul4_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 12);
-    //     let mut mul5_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul5_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 18);
-    //     let mut mul6_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul6_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 24);
-
-
-    //     let kron_name = u3u3.name();
-    //     let mul_name = cnotu3u3.name();
-    //     let module: Module<c64> = ModuleBuilder::new("test", DifferentiationLevel::Gradient)
-    //         .add_expression_with_stride(u3u3, out_utry.col_stride().try_into().unwrap())
-    //         .add_expression_with_stride(cnotu3u3, out_utry.col_stride().try_into().unwrap())
-    //         .build();
-
-    //     let kron_utry_and_grad_func = module.get_function_and_gradient(&kron_name).unwrap();
-    //     let mul_utry_and_grad_func = module.get_function_and_gradient(&mul_name).unwrap();
-
-    //     let kron1_out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(kron_out_utry.as_mut()) };
-    //     let kron1_out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(kron_out_grad.as_mut()) };
-    //     let mul1_out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(mul_out_utry.as_mut()) };
-    //     let mul1_out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(mul_out_grad.as_mut()) };
-    //     let mul2_out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(mul2_out_utry.as_mut()) };
-    //     let mul2_out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(mul2_out_grad.as_mut()) };
-    //     let mul3_out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(mul3_out_utry.as_mut()) };
-    //     let mul3_out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(mul3_out_grad.as_mut()) };
-    //     // let mul4_out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(mul4_out_utry.as_mut()) };
-    //     // let mul4_out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(mul4_out_grad.as_mut()) };
-    //     // let mul5_out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(mul5_out_utry.as_mut()) };
-    //     // let mul5_out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(mul5_out_grad.as_mut()) };
-    //     // let mul6_out_ptr = unsafe { qudit_core::matrix::matmut_to_ptr(mul6_out_utry.as_mut()) };
-    //     // let mul6_out_grad_ptr = unsafe { qudit_core::matrix::matvecmut_to_ptr(mul6_out_grad.as_mut()) };
-
-
-
-    //     let start = std::time::Instant::now();
-    //     for i in 0..n {
-    //         unsafe {
-    //             mul_utry_and_grad_func.call(params[i][0..6].as_ptr(), mul1_out_ptr, mul1_out_grad_ptr);
-    //             mul_utry_and_grad_func.call(params[i][6..12].as_ptr(), mul2_out_ptr, mul2_out_grad_ptr);
-    //             mul_utry_and_grad_func.call(params[i][12..18].as_ptr(), mul3_out_ptr, mul3_out_grad_ptr);
-    //             kron_utry_and_grad_func.call(params[i][18..24].as_ptr(), kron1_out_ptr, kron1_out_grad_ptr);
-                
-    //             qudit_core::accel::matmul_unchecked(mul_out_utry.as_ref(), kron_out_utry.as_ref(), mul4_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(mul_out_grad.mat_ref(0), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(mul_out_grad.mat_ref(1), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(mul_out_grad.mat_ref(2), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(mul_out_grad.mat_ref(3), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(mul_out_grad.mat_ref(4), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(mul_out_grad.mat_ref(5), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(5));
-    //             qudit_core::accel::matmul_unchecked(mul_out_utry.as_ref(), kron_out_grad.mat_ref(0), mul4_out_grad.mat_mut(6));
-    //             qudit_core::accel::matmul_unchecked(mul_out_utry.as_ref(), kron_out_grad.mat_ref(1), mul4_out_grad.mat_mut(7));
-    //             qudit_core::accel::matmul_unchecked(mul_out_utry.as_ref(), kron_out_grad.mat_ref(2), mul4_out_grad.mat_mut(8));
-    //             qudit_core::accel::matmul_unchecked(mul_out_utry.as_ref(), kron_out_grad.mat_ref(3), mul4_out_grad.mat_mut(9));
-    //             qudit_core::accel::matmul_unchecked(mul_out_utry.as_ref(), kron_out_grad.mat_ref(4), mul4_out_grad.mat_mut(10));
-    //             qudit_core::accel::matmul_unchecked(mul_out_utry.as_ref(), kron_out_grad.mat_ref(5), mul4_out_grad.mat_mut(11));
-
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_utry.as_ref(), mul5_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(0), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(1), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(2), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(3), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(4), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(5), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(5));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(0), mul5_out_grad.mat_mut(6));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(1), mul5_out_grad.mat_mut(7));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(2), mul5_out_grad.mat_mut(8));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(3), mul5_out_grad.mat_mut(9));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(4), mul5_out_grad.mat_mut(10));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(5), mul5_out_grad.mat_mut(11));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(6), mul5_out_grad.mat_mut(12));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(7), mul5_out_grad.mat_mut(13));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(8), mul5_out_grad.mat_mut(14));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(9), mul5_out_grad.mat_mut(15));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(10), mul5_out_grad.mat_mut(16));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(11), mul5_out_grad.mat_mut(17));
-
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_utry.as_ref(), mul6_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(0), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(1), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(2), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(3), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(4), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(5), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(5));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(0), mul6_out_grad.mat_mut(6));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(1), mul6_out_grad.mat_mut(7));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(2), mul6_out_grad.mat_mut(8));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(3), mul6_out_grad.mat_mut(9));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(4), mul6_out_grad.mat_mut(10));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(5), mul6_out_grad.mat_mut(11));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(6), mul6_out_grad.mat_mut(12));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(7), mul6_out_grad.mat_mut(13));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(8), mul6_out_grad.mat_mut(14));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(9), mul6_out_grad.mat_mut(15));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(10), mul6_out_grad.mat_mut(16));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(11), mul6_out_grad.mat_mut(17));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(12), mul6_out_grad.mat_mut(18));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(13), mul6_out_grad.mat_mut(19));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(14), mul6_out_grad.mat_mut(20));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(15), mul6_out_grad.mat_mut(21));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(16), mul6_out_grad.mat_mut(22));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(17), mul6_out_grad.mat_mut(23));
-    //         }
-    //     }
-    //     let duration = start.elapsed();
-    //     println!("Mix, Time elapsed: {:?}", duration);
-    //     println!("Mix, Time per call: {:?}", duration / n as u32);
-
-
-    //     let u3_gate = U3GateNative;
-    //     let cnot_gate = CNOTGateNative;
-
-    //     let mut u31_out_utry: Mat<c64> = Mat::zeros(2, 2);
-    //     let mut u31_out_grad: MatVec<c64> = MatVec::zeros(2, 2, 3);
-    //     let mut u32_out_utry: Mat<c64> = Mat::zeros(2, 2);
-    //     let mut u32_out_grad: MatVec<c64> = MatVec::zeros(2, 2, 3);
-    //     let mut cnot_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut cnot_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 0);
-    //     let mut kron_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut kron_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-    //     let mut mul1_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul1_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-    //     let mut mul2_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul2_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-    //     let mut mul3_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul3_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 6);
-
-
-    //     let mut mul4_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul4_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 12);
-
-    //     let mut mul5_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul5_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 18);
-
-    //     let mut mul6_out_utry: Mat<c64> = Mat::zeros(4, 4);
-    //     let mut mul6_out_grad: MatVec<c64> = MatVec::zeros(4, 4, 24);
-
-
-    //     let start = std::time::Instant::now();
-    //     for i in 0..n {
-    //         u3_gate.write_unitary_and_gradient(&params[i][0..3], u31_out_utry.as_mut(), u31_out_grad.as_mut());
-    //         u3_gate.write_unitary_and_gradient(&params[i][3..6], u32_out_utry.as_mut(), u32_out_grad.as_mut());
-    //         cnot_gate.write_unitary_and_gradient(&[], cnot_out_utry.as_mut(), cnot_out_grad.as_mut());
-            
-    //         unsafe {
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_utry.as_mut(), u31_out_utry.as_ref(), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(0), u31_out_grad.mat_ref(0), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(1), u31_out_grad.mat_ref(1), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(2), u31_out_grad.mat_ref(2), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(3), u31_out_utry.as_ref(), u32_out_grad.mat_ref(0));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(4), u31_out_utry.as_ref(), u32_out_grad.mat_ref(1));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(5), u31_out_utry.as_ref(), u32_out_grad.mat_ref(2));
-
-    //             qudit_core::accel::matmul_unchecked(kron_out_utry.as_ref(), cnot_out_utry.as_ref(), mul1_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(0), cnot_out_utry.as_ref(), mul1_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(1), cnot_out_utry.as_ref(), mul1_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(2), cnot_out_utry.as_ref(), mul1_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(3), cnot_out_utry.as_ref(), mul1_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(4), cnot_out_utry.as_ref(), mul1_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(5), cnot_out_utry.as_ref(), mul1_out_grad.mat_mut(5));
-    //         }
-
-    //         u3_gate.write_unitary_and_gradient(&params[i][6..9], u31_out_utry.as_mut(), u31_out_grad.as_mut());
-    //         u3_gate.write_unitary_and_gradient(&params[i][9..12], u32_out_utry.as_mut(), u32_out_grad.as_mut());
-    //         cnot_gate.write_unitary_and_gradient(&[], cnot_out_utry.as_mut(), cnot_out_grad.as_mut());
-            
-    //         unsafe {
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_utry.as_mut(), u31_out_utry.as_ref(), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(0), u31_out_grad.mat_ref(0), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(1), u31_out_grad.mat_ref(1), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(2), u31_out_grad.mat_ref(2), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(3), u31_out_utry.as_ref(), u32_out_grad.mat_ref(0));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(4), u31_out_utry.as_ref(), u32_out_grad.mat_ref(1));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(5), u31_out_utry.as_ref(), u32_out_grad.mat_ref(2));
-
-    //             qudit_core::accel::matmul_unchecked(kron_out_utry.as_ref(), cnot_out_utry.as_ref(), mul2_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(0), cnot_out_utry.as_ref(), mul2_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(1), cnot_out_utry.as_ref(), mul2_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(2), cnot_out_utry.as_ref(), mul2_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(3), cnot_out_utry.as_ref(), mul2_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(4), cnot_out_utry.as_ref(), mul2_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(5), cnot_out_utry.as_ref(), mul2_out_grad.mat_mut(5));
-    //         }
-
-    //         u3_gate.write_unitary_and_gradient(&params[i][12..15], u31_out_utry.as_mut(), u31_out_grad.as_mut());
-    //         u3_gate.write_unitary_and_gradient(&params[i][15..18], u32_out_utry.as_mut(), u32_out_grad.as_mut());
-
-    //         unsafe {
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_utry.as_mut(), u31_out_utry.as_ref(), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(0), u31_out_grad.mat_ref(0), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(1), u31_out_grad.mat_ref(1), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(2), u31_out_grad.mat_ref(2), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(3), u31_out_utry.as_ref(), u32_out_grad.mat_ref(0));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(4), u31_out_utry.as_ref(), u32_out_grad.mat_ref(1));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(5), u31_out_utry.as_ref(), u32_out_grad.mat_ref(2));
-
-    //             qudit_core::accel::matmul_unchecked(kron_out_utry.as_ref(), cnot_out_utry.as_ref(), mul3_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(0), cnot_out_utry.as_ref(), mul3_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(1), cnot_out_utry.as_ref(), mul3_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(2), cnot_out_utry.as_ref(), mul3_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(3), cnot_out_utry.as_ref(), mul3_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(4), cnot_out_utry.as_ref(), mul3_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(kron_out_grad.mat_ref(5), cnot_out_utry.as_ref(), mul3_out_grad.mat_mut(5));
-    //         }
-
-    //         u3_gate.write_unitary_and_gradient(&params[i][18..21], u31_out_utry.as_mut(), u31_out_grad.as_mut());
-    //         u3_gate.write_unitary_and_gradient(&params[i][21..24], u32_out_utry.as_mut(), u32_out_grad.as_mut());
-
-    //         unsafe {
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_utry.as_mut(), u31_out_utry.as_ref(), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(0), u31_out_grad.mat_ref(0), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(1), u31_out_grad.mat_ref(1), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(2), u31_out_grad.mat_ref(2), u32_out_utry.as_ref());
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(3), u31_out_utry.as_ref(), u32_out_grad.mat_ref(0));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(4), u31_out_utry.as_ref(), u32_out_grad.mat_ref(1));
-    //             qudit_core::accel::kron_sq_unchecked(kron_out_grad.mat_mut(5), u31_out_utry.as_ref(), u32_out_grad.mat_ref(2));
-    //         }
-
-    //         unsafe {
-    //             qudit_core::accel::matmul_unchecked(mul1_out_utry.as_ref(), kron_out_utry.as_ref(), mul4_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(mul1_out_grad.mat_ref(0), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_grad.mat_ref(1), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_grad.mat_ref(2), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_grad.mat_ref(3), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_grad.mat_ref(4), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_grad.mat_ref(5), kron_out_utry.as_ref(), mul4_out_grad.mat_mut(5));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_utry.as_ref(), kron_out_grad.mat_ref(0), mul4_out_grad.mat_mut(6));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_utry.as_ref(), kron_out_grad.mat_ref(1), mul4_out_grad.mat_mut(7));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_utry.as_ref(), kron_out_grad.mat_ref(2), mul4_out_grad.mat_mut(8));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_utry.as_ref(), kron_out_grad.mat_ref(3), mul4_out_grad.mat_mut(9));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_utry.as_ref(), kron_out_grad.mat_ref(4), mul4_out_grad.mat_mut(10));
-    //             qudit_core::accel::matmul_unchecked(mul1_out_utry.as_ref(), kron_out_grad.mat_ref(5), mul4_out_grad.mat_mut(11));
-
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_utry.as_ref(), mul5_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(0), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(1), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(2), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(3), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(4), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_grad.mat_ref(5), mul4_out_utry.as_ref(), mul5_out_grad.mat_mut(5));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(0), mul5_out_grad.mat_mut(6));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(1), mul5_out_grad.mat_mut(7));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(2), mul5_out_grad.mat_mut(8));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(3), mul5_out_grad.mat_mut(9));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(4), mul5_out_grad.mat_mut(10));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(5), mul5_out_grad.mat_mut(11));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(6), mul5_out_grad.mat_mut(12));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(7), mul5_out_grad.mat_mut(13));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(8), mul5_out_grad.mat_mut(14));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(9), mul5_out_grad.mat_mut(15));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(10), mul5_out_grad.mat_mut(16));
-    //             qudit_core::accel::matmul_unchecked(mul2_out_utry.as_ref(), mul4_out_grad.mat_ref(11), mul5_out_grad.mat_mut(17));
-
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_utry.as_ref(), mul6_out_utry.as_mut());
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(0), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(0));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(1), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(1));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(2), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(2));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(3), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(3));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(4), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(4));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_grad.mat_ref(5), mul5_out_utry.as_ref(), mul6_out_grad.mat_mut(5));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(0), mul6_out_grad.mat_mut(6));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(1), mul6_out_grad.mat_mut(7));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(2), mul6_out_grad.mat_mut(8));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(3), mul6_out_grad.mat_mut(9));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(4), mul6_out_grad.mat_mut(10));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(5), mul6_out_grad.mat_mut(11));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(6), mul6_out_grad.mat_mut(12));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(7), mul6_out_grad.mat_mut(13));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(8), mul6_out_grad.mat_mut(14));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(9), mul6_out_grad.mat_mut(15));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(10), mul6_out_grad.mat_mut(16));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(11), mul6_out_grad.mat_mut(17));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(12), mul6_out_grad.mat_mut(18));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(13), mul6_out_grad.mat_mut(19));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(14), mul6_out_grad.mat_mut(20));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(15), mul6_out_grad.mat_mut(21));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(16), mul6_out_grad.mat_mut(22));
-    //             qudit_core::accel::matmul_unchecked(mul3_out_utry.as_ref(), mul5_out_grad.mat_ref(17), mul6_out_grad.mat_mut(23));
-    //         }
-    //     }
-    //     let duration = start.elapsed();
-    //     println!("Native, Time elapsed: {:?}", duration);
-    //     println!("Native, Time per call: {:?}", duration / n as u32);
-    // }
+    #[test]
+    fn test_u3_gate() {
+        let (theta, phi, lambda) = (1.7, 2.3, 3.1);
+        let utry: UnitaryMatrix<c64> = U3Gate.gen_expr().get_unitary(&[theta, phi, lambda]);
+        let (sin, cos) = (theta / 2.0).sin_cos();
+        let cis = |angle: f64| c64::new(angle.cos(), angle.sin());
+        crate::assert_unitary_close!(utry, [
+            [c64::new(cos, 0.0), -cis(lambda) * sin],
+            [cis(phi) * sin, cis(phi + lambda) * cos],
+        ]);
+    }
 }
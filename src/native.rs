@@ -0,0 +1,297 @@
+use std::f64::consts::PI;
+
+use qudit_core::c64;
+use qudit_core::matrix::{MatMut, MatVecMut};
+use qudit_core::QuditSystem;
+
+use crate::ControlledGate;
+use crate::Gate;
+use crate::HGate;
+use crate::PGate;
+use crate::U3Gate;
+use crate::XGate;
+
+/// A gate that can write its unitary and gradient in closed form.
+///
+/// This is an alternative to compiling a gate's
+/// [`qudit_expr::UnitaryExpression`]: the unitary and gradient are written
+/// directly into the output buffers, without going through expression
+/// compilation. The output buffers are fully overwritten.
+pub trait NativeUnitaryFn {
+    /// Write the unitary of the gate at `params` into `utry`.
+    fn write_unitary(&self, params: &[f64], utry: MatMut<c64>);
+
+    /// Write the partial derivative of the unitary of the gate at `params`
+    /// with respect to parameter `k` into `out`.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is not less than the number of parameters of the gate.
+    fn write_partial(&self, params: &[f64], k: usize, out: MatMut<c64>);
+
+    /// Write the unitary and gradient of the gate at `params` into `utry`
+    /// and `grad`.
+    ///
+    /// The gradient has one matrix per parameter, in parameter order.
+    fn write_unitary_and_gradient(
+        &self,
+        params: &[f64],
+        utry: MatMut<c64>,
+        mut grad: MatVecMut<c64>,
+    ) {
+        self.write_unitary(params, utry);
+        for k in 0..params.len() {
+            self.write_partial(params, k, grad.mat_mut(k));
+        }
+    }
+}
+
+impl Gate {
+    /// Returns the native implementation of this gate, if one exists.
+    ///
+    /// Controlled gates are only native if the gate being controlled is;
+    /// otherwise, `None` is returned.
+    pub fn as_native(&self) -> Option<Box<dyn NativeUnitaryFn + '_>> {
+        match self {
+            Gate::HGate(gate) => Some(Box::new(gate.clone())),
+            Gate::PGate(gate) => Some(Box::new(gate.clone())),
+            Gate::XGate(gate) => Some(Box::new(gate.clone())),
+            Gate::U3Gate(gate) => Some(Box::new(*gate)),
+            Gate::Controlled(gate) => {
+                let native = gate.gate().as_native()?;
+                Some(Box::new(NativeControlledGate::new(gate, native)))
+            },
+            _ => None,
+        }
+    }
+}
+
+fn write_zeros(mut mat: MatMut<c64>) {
+    for j in 0..mat.ncols() {
+        for i in 0..mat.nrows() {
+            mat[(i, j)] = c64::new(0.0, 0.0);
+        }
+    }
+}
+
+fn write_identity(mut mat: MatMut<c64>) {
+    for j in 0..mat.ncols() {
+        for i in 0..mat.nrows() {
+            mat[(i, j)] = c64::new(if i == j { 1.0 } else { 0.0 }, 0.0);
+        }
+    }
+}
+
+#[inline]
+fn cis(theta: f64) -> c64 {
+    c64::new(theta.cos(), theta.sin())
+}
+
+impl NativeUnitaryFn for HGate {
+    fn write_unitary(&self, _params: &[f64], mut utry: MatMut<c64>) {
+        let norm = 1.0 / (self.radix as f64).sqrt();
+        for i in 0..self.radix {
+            for j in 0..self.radix {
+                let k = (i * j) % self.radix;
+                let omega = cis(2.0 * PI * k as f64 / self.radix as f64);
+                utry[(i, j)] = omega * norm;
+            }
+        }
+    }
+
+    fn write_partial(&self, _params: &[f64], _k: usize, _out: MatMut<c64>) {
+        panic!("Expected a parameter index less than the number of parameters.");
+    }
+}
+
+impl NativeUnitaryFn for XGate {
+    fn write_unitary(&self, _params: &[f64], mut utry: MatMut<c64>) {
+        write_zeros(utry.rb_mut());
+        for j in 0..self.radix {
+            utry[((j + 1) % self.radix, j)] = c64::new(1.0, 0.0);
+        }
+    }
+
+    fn write_partial(&self, _params: &[f64], _k: usize, _out: MatMut<c64>) {
+        panic!("Expected a parameter index less than the number of parameters.");
+    }
+}
+
+impl NativeUnitaryFn for PGate {
+    fn write_unitary(&self, params: &[f64], mut utry: MatMut<c64>) {
+        write_identity(utry.rb_mut());
+        for (k, theta) in params.iter().enumerate() {
+            utry[(k + 1, k + 1)] = cis(*theta);
+        }
+    }
+
+    fn write_partial(&self, params: &[f64], k: usize, mut out: MatMut<c64>) {
+        write_zeros(out.rb_mut());
+        out[(k + 1, k + 1)] = c64::new(0.0, 1.0) * cis(params[k]);
+    }
+}
+
+impl NativeUnitaryFn for U3Gate {
+    fn write_unitary(&self, params: &[f64], mut utry: MatMut<c64>) {
+        let (sin, cos) = (params[0] / 2.0).sin_cos();
+        let e1 = cis(params[1]);
+        let e2 = cis(params[2]);
+        let e12 = cis(params[1] + params[2]);
+        utry[(0, 0)] = c64::new(cos, 0.0);
+        utry[(0, 1)] = -e2 * sin;
+        utry[(1, 0)] = e1 * sin;
+        utry[(1, 1)] = e12 * cos;
+    }
+
+    fn write_partial(&self, params: &[f64], k: usize, mut out: MatMut<c64>) {
+        let (sin, cos) = (params[0] / 2.0).sin_cos();
+        let i = c64::new(0.0, 1.0);
+        let e1 = cis(params[1]);
+        let e2 = cis(params[2]);
+        let e12 = cis(params[1] + params[2]);
+
+        match k {
+            0 => {
+                out[(0, 0)] = c64::new(-sin / 2.0, 0.0);
+                out[(0, 1)] = -e2 * (cos / 2.0);
+                out[(1, 0)] = e1 * (cos / 2.0);
+                out[(1, 1)] = -e12 * (sin / 2.0);
+            },
+            1 => {
+                out[(0, 0)] = c64::new(0.0, 0.0);
+                out[(0, 1)] = c64::new(0.0, 0.0);
+                out[(1, 0)] = i * e1 * sin;
+                out[(1, 1)] = i * e12 * cos;
+            },
+            2 => {
+                out[(0, 0)] = c64::new(0.0, 0.0);
+                out[(0, 1)] = -i * e2 * sin;
+                out[(1, 0)] = c64::new(0.0, 0.0);
+                out[(1, 1)] = i * e12 * cos;
+            },
+            _ => panic!("Expected a parameter index less than the number of parameters."),
+        }
+    }
+}
+
+/// A controlled gate whose controlled gate has a native implementation.
+///
+/// This is only constructed by [`Gate::as_native`], which checks that the
+/// gate being controlled is native.
+struct NativeControlledGate<'a> {
+    // The native implementation of the gate being controlled.
+    native: Box<dyn NativeUnitaryFn + 'a>,
+
+    // The dimension of the gate being controlled.
+    gate_dim: usize,
+
+    // The offsets of the diagonal blocks activated by the control levels.
+    offsets: Vec<usize>,
+}
+
+impl<'a> NativeControlledGate<'a> {
+    fn new(gate: &ControlledGate, native: Box<dyn NativeUnitaryFn + 'a>) -> Self {
+        let gate_dim = gate.gate().dimension();
+        let offsets = ControlledGate::cartesian_product(gate.control_levels().to_vec())
+            .into_iter()
+            .map(|levels| gate.control_radices().compress(&levels) * gate_dim)
+            .collect();
+        NativeControlledGate { native, gate_dim, offsets }
+    }
+
+    /// Copy the first activated block of `out` into the other activated
+    /// blocks.
+    fn copy_first_block(&self, mut out: MatMut<c64>) {
+        if let Some((&first, rest)) = self.offsets.split_first() {
+            for &offset in rest {
+                for j in 0..self.gate_dim {
+                    for i in 0..self.gate_dim {
+                        out[(offset + i, offset + j)] = out[(first + i, first + j)];
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl NativeUnitaryFn for NativeControlledGate<'_> {
+    fn write_unitary(&self, params: &[f64], mut utry: MatMut<c64>) {
+        write_identity(utry.rb_mut());
+        if let Some(&first) = self.offsets.first() {
+            let block = utry.rb_mut().submatrix_mut(first, first, self.gate_dim, self.gate_dim);
+            self.native.write_unitary(params, block);
+            self.copy_first_block(utry);
+        }
+    }
+
+    fn write_partial(&self, params: &[f64], k: usize, mut out: MatMut<c64>) {
+        write_zeros(out.rb_mut());
+        if let Some(&first) = self.offsets.first() {
+            let block = out.rb_mut().submatrix_mut(first, first, self.gate_dim, self.gate_dim);
+            self.native.write_partial(params, k, block);
+            self.copy_first_block(out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qudit_core::matrix::{Mat, MatVec};
+    use qudit_core::radices;
+    use qudit_core::HasParams;
+    use qudit_core::unitary::{DifferentiableUnitaryFn, UnitaryFn, UnitaryMatrix};
+    use qudit_expr::UnitaryExpressionGenerator;
+
+    fn assert_native_matches_expr(gate: Gate) {
+        let native = gate.as_native().unwrap();
+        let expr = gate.gen_expr();
+        let dim = gate.dimension();
+        let num_params = gate.num_params();
+        let params = gate.random_params(&mut rand::thread_rng());
+
+        let expected: UnitaryMatrix<c64> = expr.get_unitary(&params);
+        let expected_grad: MatVec<c64> = expr.get_gradient(&params);
+
+        let mut utry: Mat<c64> = Mat::zeros(dim, dim);
+        let mut grad: MatVec<c64> = MatVec::zeros(dim, dim, num_params);
+        native.write_unitary_and_gradient(&params, utry.as_mut(), grad.as_mut());
+
+        for i in 0..dim {
+            for j in 0..dim {
+                assert!((utry[(i, j)] - expected[(i, j)]).norm() < 1e-10);
+                for k in 0..num_params {
+                    let diff = grad.mat_ref(k)[(i, j)] - expected_grad.mat_ref(k)[(i, j)];
+                    assert!(diff.norm() < 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_native_matches_expr() {
+        for radix in 2..=5 {
+            assert_native_matches_expr(Gate::H(radix));
+            assert_native_matches_expr(Gate::X(radix));
+            assert_native_matches_expr(Gate::P(radix));
+        }
+        assert_native_matches_expr(Gate::U3());
+        assert_native_matches_expr(Gate::CX());
+        assert_native_matches_expr(Gate::CP());
+        assert_native_matches_expr(Gate::Controlled(ControlledGate::from_gate(
+            U3Gate,
+            radices![3],
+            vec![vec![1, 2]],
+        )));
+    }
+
+    #[test]
+    fn test_controlled_gate_without_native_kernel() {
+        let gate = Gate::Controlled(ControlledGate::from_gate(
+            Gate::RZZ(2),
+            radices![2],
+            vec![vec![1]],
+        ));
+        assert!(gate.as_native().is_none());
+    }
+}
@@ -0,0 +1,501 @@
+use std::f64::consts::PI;
+
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
+use qudit_expr::UnitaryExpression;
+use qudit_expr::UnitaryExpressionGenerator;
+
+use crate::utils::negate_params;
+use crate::ParamSpec;
+use crate::QuditGate;
+
+/// The two-qubit XX Ising interaction gate.
+///
+/// The RXX gate is given by $\exp(-i\frac{\theta}{2} X \otimes X)$, or the
+/// following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     \cos{\frac{\theta}{2}} & 0 & 0 & -i\sin{\frac{\theta}{2}} \\\\
+///     0 & \cos{\frac{\theta}{2}} & -i\sin{\frac{\theta}{2}} & 0 \\\\
+///     0 & -i\sin{\frac{\theta}{2}} & \cos{\frac{\theta}{2}} & 0 \\\\
+///     -i\sin{\frac{\theta}{2}} & 0 & 0 & \cos{\frac{\theta}{2}} \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.RXXGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RXXGate;
+
+impl RXXGate {
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is the interaction by the negated angle. The returned
+    /// expression takes the same parameter as this gate.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        let proto = "utry RXXdg<2, 2>(θ)";
+        UnitaryExpression::new(proto.to_owned() + "{" + &self.gen_inverse_body() + "}")
+    }
+
+    /// The body of this gate's expression, in terms of its parameter.
+    pub(crate) fn gen_body(&self) -> String {
+        "[
+                [cos(θ/2), 0, 0, ~i*sin(θ/2)],
+                [0, cos(θ/2), ~i*sin(θ/2), 0],
+                [0, ~i*sin(θ/2), cos(θ/2), 0],
+                [~i*sin(θ/2), 0, 0, cos(θ/2)]
+        ]".to_string()
+    }
+
+    /// The body of the inverse's expression, with the parameter negated.
+    pub(crate) fn gen_inverse_body(&self) -> String {
+        negate_params(&self.gen_body(), &["θ"])
+    }
+}
+
+impl UnitaryExpressionGenerator for RXXGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry RXX<2, 2>(θ)";
+        UnitaryExpression::new(proto.to_owned() + "{" + &self.gen_body() + "}")
+    }
+}
+
+impl HasParams for RXXGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        1
+    }
+}
+
+impl QuditSystem for RXXGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2, 2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        2
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        4
+    }
+}
+
+impl QuditGate for RXXGate {
+    fn name(&self) -> String {
+        "RXX".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec!["θ".to_string()]
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        vec![ParamSpec::half_angle("θ")]
+    }
+}
+
+/// The two-qubit YY Ising interaction gate.
+///
+/// The RYY gate is given by $\exp(-i\frac{\theta}{2} Y \otimes Y)$, or the
+/// following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     \cos{\frac{\theta}{2}} & 0 & 0 & i\sin{\frac{\theta}{2}} \\\\
+///     0 & \cos{\frac{\theta}{2}} & -i\sin{\frac{\theta}{2}} & 0 \\\\
+///     0 & -i\sin{\frac{\theta}{2}} & \cos{\frac{\theta}{2}} & 0 \\\\
+///     i\sin{\frac{\theta}{2}} & 0 & 0 & \cos{\frac{\theta}{2}} \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.RYYGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RYYGate;
+
+impl RYYGate {
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is the interaction by the negated angle. The returned
+    /// expression takes the same parameter as this gate.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        let proto = "utry RYYdg<2, 2>(θ)";
+        UnitaryExpression::new(proto.to_owned() + "{" + &self.gen_inverse_body() + "}")
+    }
+
+    /// The body of this gate's expression, in terms of its parameter.
+    pub(crate) fn gen_body(&self) -> String {
+        "[
+                [cos(θ/2), 0, 0, i*sin(θ/2)],
+                [0, cos(θ/2), ~i*sin(θ/2), 0],
+                [0, ~i*sin(θ/2), cos(θ/2), 0],
+                [i*sin(θ/2), 0, 0, cos(θ/2)]
+        ]".to_string()
+    }
+
+    /// The body of the inverse's expression, with the parameter negated.
+    pub(crate) fn gen_inverse_body(&self) -> String {
+        negate_params(&self.gen_body(), &["θ"])
+    }
+}
+
+impl UnitaryExpressionGenerator for RYYGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry RYY<2, 2>(θ)";
+        UnitaryExpression::new(proto.to_owned() + "{" + &self.gen_body() + "}")
+    }
+}
+
+impl HasParams for RYYGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        1
+    }
+}
+
+impl QuditSystem for RYYGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2, 2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        2
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        4
+    }
+}
+
+impl QuditGate for RYYGate {
+    fn name(&self) -> String {
+        "RYY".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec!["θ".to_string()]
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        vec![ParamSpec::half_angle("θ")]
+    }
+}
+
+/// The two-qudit ZZ Ising interaction gate.
+///
+/// The qubit RZZ gate is given by $\exp(-i\frac{\theta}{2} Z \otimes Z)$,
+/// or the following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     \exp({-i\frac{\theta}{2}}) & 0 & 0 & 0 \\\\
+///     0 & \exp({i\frac{\theta}{2}}) & 0 & 0 \\\\
+///     0 & 0 & \exp({i\frac{\theta}{2}}) & 0 \\\\
+///     0 & 0 & 0 & \exp({-i\frac{\theta}{2}}) \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// For qudits, the Pauli product is replaced by the Hermitian part of the
+/// generalized clock coupling $Z \otimes Z^\dagger$:
+///
+/// $$
+/// RZZ = \exp\Big(-i\frac{\theta}{2} \cdot
+///     \frac{Z \otimes Z^\dagger + Z^\dagger \otimes Z}{2}\Big)
+///     = \sum_{a, b} \exp\Big(-i\frac{\theta}{2}
+///     \cos\Big(\frac{2\pi(a - b)}{d}\Big)\Big) \ket{ab}\bra{ab}
+/// $$
+///
+/// where $d$ is the number of levels of both qudits. This reduces to the
+/// qubit gate when $d = 2$.
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.RZZGate.html>
+/// - <https://arxiv.org/pdf/2302.07966.pdf>
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct RZZGate {
+    pub radix: usize,
+}
+
+impl RZZGate {
+    pub fn new(radix: usize) -> Self {
+        Self { radix }
+    }
+
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is the interaction by the negated angle. The returned
+    /// expression takes the same parameter as this gate.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        UnitaryExpression::new(self.gen_proto("RZZdg") + "{" + &self.gen_inverse_body() + "}")
+    }
+
+    /// The body of this gate's expression, in terms of its parameter.
+    pub(crate) fn gen_body(&self) -> String {
+        let dim = self.radix * self.radix;
+        let mut body = "[".to_string();
+        for i in 0..dim {
+            body += "[";
+            for j in 0..dim {
+                if i != j {
+                    body += "0, ";
+                    continue;
+                }
+
+                let a = i / self.radix;
+                let b = i % self.radix;
+                let k = (a + self.radix - b) % self.radix;
+                if k == 0 {
+                    body += "e^(~i*θ/2), ";
+                } else if 2 * k == self.radix {
+                    body += "e^(i*θ/2), ";
+                } else {
+                    body += &format!("e^(~i*θ/2*cos(2*π*{}/{})), ", k, self.radix);
+                }
+            }
+            body += "],";
+        }
+        body += "]";
+        body
+    }
+
+    /// The body of the inverse's expression, with the parameter negated.
+    pub(crate) fn gen_inverse_body(&self) -> String {
+        negate_params(&self.gen_body(), &["θ"])
+    }
+
+    fn gen_proto(&self, name: &str) -> String {
+        format!("utry {}<{}, {}>(θ)", name, self.radix, self.radix)
+    }
+}
+
+impl UnitaryExpressionGenerator for RZZGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        UnitaryExpression::new(self.gen_proto("RZZ") + "{" + &self.gen_body() + "}")
+    }
+}
+
+impl HasParams for RZZGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        1
+    }
+}
+
+impl QuditSystem for RZZGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix, self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        2
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix * self.radix
+    }
+}
+
+impl QuditGate for RZZGate {
+    fn name(&self) -> String {
+        "RZZ".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec!["θ".to_string()]
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        // The phases are multiples of θ/2 by cos(2πk/d), which are all
+        // rational only for d in {2, 3, 4, 6}; otherwise, the unitary is
+        // not periodic in θ.
+        match self.radix {
+            2 | 4 => vec![ParamSpec::half_angle("θ")],
+            3 | 6 => vec![ParamSpec::new("θ", 0.0, 8.0 * PI, true)],
+            _ => vec![ParamSpec::new("θ", 0.0, 4.0 * PI, false)],
+        }
+    }
+}
+
+/// The two-qubit ZX cross-resonance interaction gate.
+///
+/// The RZX gate is given by $\exp(-i\frac{\theta}{2} Z \otimes X)$, or the
+/// following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     \cos{\frac{\theta}{2}} & -i\sin{\frac{\theta}{2}} & 0 & 0 \\\\
+///     -i\sin{\frac{\theta}{2}} & \cos{\frac{\theta}{2}} & 0 & 0 \\\\
+///     0 & 0 & \cos{\frac{\theta}{2}} & i\sin{\frac{\theta}{2}} \\\\
+///     0 & 0 & i\sin{\frac{\theta}{2}} & \cos{\frac{\theta}{2}} \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.RZXGate.html>
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RZXGate;
+
+impl RZXGate {
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is the interaction by the negated angle. The returned
+    /// expression takes the same parameter as this gate.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        let proto = "utry RZXdg<2, 2>(θ)";
+        UnitaryExpression::new(proto.to_owned() + "{" + &self.gen_inverse_body() + "}")
+    }
+
+    /// The body of this gate's expression, in terms of its parameter.
+    pub(crate) fn gen_body(&self) -> String {
+        "[
+                [cos(θ/2), ~i*sin(θ/2), 0, 0],
+                [~i*sin(θ/2), cos(θ/2), 0, 0],
+                [0, 0, cos(θ/2), i*sin(θ/2)],
+                [0, 0, i*sin(θ/2), cos(θ/2)]
+        ]".to_string()
+    }
+
+    /// The body of the inverse's expression, with the parameter negated.
+    pub(crate) fn gen_inverse_body(&self) -> String {
+        negate_params(&self.gen_body(), &["θ"])
+    }
+}
+
+impl UnitaryExpressionGenerator for RZXGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        let proto = "utry RZX<2, 2>(θ)";
+        UnitaryExpression::new(proto.to_owned() + "{" + &self.gen_body() + "}")
+    }
+}
+
+impl HasParams for RZXGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        1
+    }
+}
+
+impl QuditSystem for RZXGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2, 2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        2
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        4
+    }
+}
+
+impl QuditGate for RZXGate {
+    fn name(&self) -> String {
+        "RZX".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec!["θ".to_string()]
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        vec![ParamSpec::half_angle("θ")]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    use super::*;
+
+    const THETA: f64 = 0.7;
+
+    #[inline]
+    fn cis(theta: f64) -> c64 {
+        c64::new(theta.cos(), theta.sin())
+    }
+
+    #[test]
+    fn test_rxx_gate() {
+        let (s, c) = (THETA / 2.0).sin_cos();
+        let is = c64::new(0.0, s);
+        let utry: UnitaryMatrix<c64> = RXXGate.gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [c, 0.0, 0.0, -is],
+            [0.0, c, -is, 0.0],
+            [0.0, -is, c, 0.0],
+            [-is, 0.0, 0.0, c],
+        ]);
+    }
+
+    #[test]
+    fn test_ryy_gate() {
+        let (s, c) = (THETA / 2.0).sin_cos();
+        let is = c64::new(0.0, s);
+        let utry: UnitaryMatrix<c64> = RYYGate.gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [c, 0.0, 0.0, is],
+            [0.0, c, -is, 0.0],
+            [0.0, -is, c, 0.0],
+            [is, 0.0, 0.0, c],
+        ]);
+    }
+
+    #[test]
+    fn test_rzx_gate() {
+        let (s, c) = (THETA / 2.0).sin_cos();
+        let is = c64::new(0.0, s);
+        let utry: UnitaryMatrix<c64> = RZXGate.gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [c, -is, 0.0, 0.0],
+            [-is, c, 0.0, 0.0],
+            [0.0, 0.0, c, is],
+            [0.0, 0.0, is, c],
+        ]);
+    }
+
+    #[test]
+    fn test_qubit_rzz_gate() {
+        let (m, p) = (cis(-THETA / 2.0), cis(THETA / 2.0));
+        let utry: UnitaryMatrix<c64> = RZZGate::new(2).gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [m, 0.0, 0.0, 0.0],
+            [0.0, p, 0.0, 0.0],
+            [0.0, 0.0, p, 0.0],
+            [0.0, 0.0, 0.0, m],
+        ]);
+    }
+
+    #[test]
+    fn test_qutrit_rzz_gate() {
+        // cos(2πk/3) = -1/2 for k != 0, so unequal levels pick up e^(iθ/4).
+        let (m, p) = (cis(-THETA / 2.0), cis(THETA / 4.0));
+        let utry: UnitaryMatrix<c64> = RZZGate::new(3).gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [m, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, p, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, p, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, p, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, m, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, p, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, p, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, p, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, m],
+        ]);
+    }
+}
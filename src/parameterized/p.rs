@@ -1,6 +1,10 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
 use qudit_expr::UnitaryExpression;
 use qudit_expr::UnitaryExpressionGenerator;
 
+use crate::utils::negate_params;
+use crate::QuditGate;
+
 /// The single-qudit phase gate.
 ///
 /// The common qubit phase gate is given by the following matrix:
@@ -38,18 +42,17 @@ impl PGate {
     pub fn new(radix: usize) -> Self {
         Self { radix }
     }
-}
 
-impl UnitaryExpressionGenerator for PGate {
-    fn gen_expr(&self) -> UnitaryExpression {
-        let mut proto = format!("utry P<{}>(", self.radix);
-        for i in 0..self.radix - 1 {
-            proto += "θ";
-            proto += &i.to_string();
-            proto += ", ";
-        }
-        proto += ")";
-        
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is the phase gate with negated phases, $P(-\theta)$. The
+    /// returned expression takes the same parameters as this gate.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        UnitaryExpression::new(self.gen_proto("Pdg") + "{" + &self.gen_inverse_body() + "}")
+    }
+
+    /// The body of this gate's expression, in terms of its parameters.
+    pub(crate) fn gen_body(&self) -> String {
         let mut body = "".to_string();
         body += "[";
         for i in 0..self.radix {
@@ -68,8 +71,95 @@ impl UnitaryExpressionGenerator for PGate {
             body += "],";
         }
         body += "]";
+        body
+    }
 
-        UnitaryExpression::new(proto + "{" + &body + "}")
+    /// The body of the inverse's expression, with the parameters negated.
+    pub(crate) fn gen_inverse_body(&self) -> String {
+        negate_params(&self.gen_body(), &self.param_names())
+    }
+
+    fn gen_proto(&self, name: &str) -> String {
+        let mut proto = format!("utry {}<{}>(", name, self.radix);
+        for i in 0..self.radix - 1 {
+            proto += "θ";
+            proto += &i.to_string();
+            proto += ", ";
+        }
+        proto += ")";
+        proto
     }
 }
 
+impl UnitaryExpressionGenerator for PGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        UnitaryExpression::new(self.gen_proto("P") + "{" + &self.gen_body() + "}")
+    }
+}
+
+impl HasParams for PGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        self.radix - 1
+    }
+}
+
+impl QuditSystem for PGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix
+    }
+}
+
+impl QuditGate for PGate {
+    fn name(&self) -> String {
+        "P".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        (0..self.radix - 1).map(|i| format!("θ{}", i)).collect()
+    }
+}
+
+#[cfg(feature = "proptest")]
+pub mod strategies {
+    use proptest::prelude::*;
+    use proptest::strategy::BoxedStrategy;
+
+    use super::*;
+    use crate::strategies::{ArbitraryGateWithRadices, RadixBounds};
+    use crate::utils::radices_to_vec;
+    use crate::Gate;
+
+    impl Arbitrary for PGate {
+        type Parameters = RadixBounds;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(bounds: Self::Parameters) -> Self::Strategy {
+            bounds.radix().prop_map(PGate::new).boxed()
+        }
+    }
+
+    impl ArbitraryGateWithRadices for PGate {
+        fn arbitrary_with_radices(radices: QuditRadices) -> Option<BoxedStrategy<Gate>> {
+            Self::arbitrary_with_radices_no_rec(radices)
+        }
+
+        fn arbitrary_with_radices_no_rec(radices: QuditRadices) -> Option<BoxedStrategy<Gate>> {
+            match radices_to_vec(&radices)[..] {
+                [radix] => Some(Just(Gate::P(radix)).boxed()),
+                _ => None,
+            }
+        }
+    }
+}
@@ -0,0 +1,481 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
+use qudit_expr::UnitaryExpression;
+use qudit_expr::UnitaryExpressionGenerator;
+
+use crate::utils::negate_params;
+use crate::ParamSpec;
+use crate::QuditGate;
+
+/// The name of a rotation acting on the `levels` subspace.
+///
+/// Qubit rotations keep the plain name, while qudit rotations are
+/// suffixed with the levels they act on.
+fn subspace_rotation_name(
+    name: &str,
+    radix: usize,
+    levels: (usize, usize),
+) -> String {
+    if radix == 2 && levels == (0, 1) {
+        name.to_string()
+    } else {
+        format!("{}_{}_{}", name, levels.0, levels.1)
+    }
+}
+
+/// Build the body of a rotation acting on the `levels` subspace.
+///
+/// The `block` is placed on the rows and columns given by `levels` and
+/// the identity is placed everywhere else.
+fn subspace_rotation_body(
+    radix: usize,
+    levels: (usize, usize),
+    block: [[&str; 2]; 2],
+) -> String {
+    let index = |level: usize| {
+        if level == levels.0 {
+            Some(0)
+        } else if level == levels.1 {
+            Some(1)
+        } else {
+            None
+        }
+    };
+
+    let mut body = "[".to_string();
+    for i in 0..radix {
+        body += "[";
+        for j in 0..radix {
+            match (index(i), index(j)) {
+                (Some(bi), Some(bj)) => {
+                    body += block[bi][bj];
+                    body += ", ";
+                }
+                (None, None) if i == j => body += "1, ",
+                _ => body += "0, ",
+            }
+        }
+        body += "],";
+    }
+    body += "]";
+    body
+}
+
+/// Build the expression for a rotation acting on the `levels` subspace
+/// from its `body`.
+fn subspace_rotation_expr(
+    name: &str,
+    radix: usize,
+    levels: (usize, usize),
+    body: &str,
+) -> UnitaryExpression {
+    let name = subspace_rotation_name(name, radix, levels);
+    let proto = format!("utry {}<{}>(θ)", name, radix);
+    UnitaryExpression::new(proto + "{" + body + "}")
+}
+
+/// Check that `levels` selects two distinct levels of a `radix`-level qudit.
+fn check_levels(radix: usize, levels: (usize, usize)) {
+    if levels.0 >= radix || levels.1 >= radix {
+        panic!("Expected rotation levels to be less than the number of levels.");
+    }
+
+    if levels.0 == levels.1 {
+        panic!("Expected rotation levels to be distinct.");
+    }
+}
+
+/// The RX rotation on the two selected levels.
+const RX_BLOCK: [[&str; 2]; 2] = [
+    ["cos(θ/2)", "~i*sin(θ/2)"],
+    ["~i*sin(θ/2)", "cos(θ/2)"],
+];
+
+/// The single-qudit X rotation gate.
+///
+/// The common qubit RX gate is given by the following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     \cos{\frac{\theta}{2}} & -i\sin{\frac{\theta}{2}} \\\\
+///     -i\sin{\frac{\theta}{2}} & \cos{\frac{\theta}{2}} \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// For qudits, the rotation acts on the two-dimensional subspace spanned
+/// by the levels $\ket{i}$ and $\ket{j}$ selected by `levels`, and as the
+/// identity on all other levels. For example, the qutrit RX gate acting
+/// on levels $(0, 2)$ is given by:
+///
+/// $$
+/// \begin{pmatrix}
+///     \cos{\frac{\theta}{2}} & 0 & -i\sin{\frac{\theta}{2}} \\\\
+///     0 & 1 & 0 \\\\
+///     -i\sin{\frac{\theta}{2}} & 0 & \cos{\frac{\theta}{2}} \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.RXGate.html>
+/// - <https://arxiv.org/pdf/2008.00959.pdf>
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct RXGate {
+    pub radix: usize,
+    pub levels: (usize, usize),
+}
+
+impl RXGate {
+    /// Construct an RXGate acting on the `levels` subspace of a qudit.
+    ///
+    /// # Panics
+    ///
+    /// * If either level is greater than or equal to `radix`.
+    ///
+    /// * If both levels are the same.
+    pub fn new(radix: usize, levels: (usize, usize)) -> Self {
+        check_levels(radix, levels);
+        Self { radix, levels }
+    }
+
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is the rotation by the negated angle, $RX(-\theta)$.
+    /// The returned expression takes the same parameter as this gate.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        subspace_rotation_expr("RXdg", self.radix, self.levels, &self.gen_inverse_body())
+    }
+
+    /// The body of this gate's expression, in terms of its parameter.
+    pub(crate) fn gen_body(&self) -> String {
+        subspace_rotation_body(self.radix, self.levels, RX_BLOCK)
+    }
+
+    /// The body of the inverse's expression, with the parameter negated.
+    pub(crate) fn gen_inverse_body(&self) -> String {
+        negate_params(&self.gen_body(), &["θ"])
+    }
+}
+
+impl UnitaryExpressionGenerator for RXGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        subspace_rotation_expr("RX", self.radix, self.levels, &self.gen_body())
+    }
+}
+
+impl HasParams for RXGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        1
+    }
+}
+
+impl QuditSystem for RXGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix
+    }
+}
+
+impl QuditGate for RXGate {
+    fn name(&self) -> String {
+        subspace_rotation_name("RX", self.radix, self.levels)
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec!["θ".to_string()]
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        vec![ParamSpec::half_angle("θ")]
+    }
+}
+
+/// The RY rotation on the two selected levels.
+const RY_BLOCK: [[&str; 2]; 2] = [
+    ["cos(θ/2)", "~sin(θ/2)"],
+    ["sin(θ/2)", "cos(θ/2)"],
+];
+
+/// The single-qudit Y rotation gate.
+///
+/// The common qubit RY gate is given by the following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     \cos{\frac{\theta}{2}} & -\sin{\frac{\theta}{2}} \\\\
+///     \sin{\frac{\theta}{2}} & \cos{\frac{\theta}{2}} \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// For qudits, this is the Givens rotation acting on the two-dimensional
+/// subspace spanned by the levels $\ket{i}$ and $\ket{j}$ selected by
+/// `levels`, and as the identity on all other levels.
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.RYGate.html>
+/// - <https://arxiv.org/pdf/2008.00959.pdf>
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct RYGate {
+    pub radix: usize,
+    pub levels: (usize, usize),
+}
+
+impl RYGate {
+    /// Construct an RYGate acting on the `levels` subspace of a qudit.
+    ///
+    /// # Panics
+    ///
+    /// * If either level is greater than or equal to `radix`.
+    ///
+    /// * If both levels are the same.
+    pub fn new(radix: usize, levels: (usize, usize)) -> Self {
+        check_levels(radix, levels);
+        Self { radix, levels }
+    }
+
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is the rotation by the negated angle, $RY(-\theta)$.
+    /// The returned expression takes the same parameter as this gate.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        subspace_rotation_expr("RYdg", self.radix, self.levels, &self.gen_inverse_body())
+    }
+
+    /// The body of this gate's expression, in terms of its parameter.
+    pub(crate) fn gen_body(&self) -> String {
+        subspace_rotation_body(self.radix, self.levels, RY_BLOCK)
+    }
+
+    /// The body of the inverse's expression, with the parameter negated.
+    pub(crate) fn gen_inverse_body(&self) -> String {
+        negate_params(&self.gen_body(), &["θ"])
+    }
+}
+
+impl UnitaryExpressionGenerator for RYGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        subspace_rotation_expr("RY", self.radix, self.levels, &self.gen_body())
+    }
+}
+
+impl HasParams for RYGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        1
+    }
+}
+
+impl QuditSystem for RYGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix
+    }
+}
+
+impl QuditGate for RYGate {
+    fn name(&self) -> String {
+        subspace_rotation_name("RY", self.radix, self.levels)
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec!["θ".to_string()]
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        vec![ParamSpec::half_angle("θ")]
+    }
+}
+
+/// The RZ rotation on the two selected levels.
+const RZ_BLOCK: [[&str; 2]; 2] = [
+    ["e^(~i*θ/2)", "0"],
+    ["0", "e^(i*θ/2)"],
+];
+
+/// The single-qudit Z rotation gate.
+///
+/// The common qubit RZ gate is given by the following matrix:
+///
+/// $$
+/// \begin{pmatrix}
+///     \exp({-i\frac{\theta}{2}}) & 0 \\\\
+///     0 & \exp({i\frac{\theta}{2}}) \\\\
+/// \end{pmatrix}
+/// $$
+///
+/// For qudits, the rotation acts on the two-dimensional subspace spanned
+/// by the levels $\ket{i}$ and $\ket{j}$ selected by `levels`, and as the
+/// identity on all other levels.
+///
+/// References:
+/// - <https://qiskit.org/documentation/stubs/qiskit.circuit.library.RZGate.html>
+/// - <https://arxiv.org/pdf/2008.00959.pdf>
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct RZGate {
+    pub radix: usize,
+    pub levels: (usize, usize),
+}
+
+impl RZGate {
+    /// Construct an RZGate acting on the `levels` subspace of a qudit.
+    ///
+    /// # Panics
+    ///
+    /// * If either level is greater than or equal to `radix`.
+    ///
+    /// * If both levels are the same.
+    pub fn new(radix: usize, levels: (usize, usize)) -> Self {
+        check_levels(radix, levels);
+        Self { radix, levels }
+    }
+
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is the rotation by the negated angle, $RZ(-\theta)$.
+    /// The returned expression takes the same parameter as this gate.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        subspace_rotation_expr("RZdg", self.radix, self.levels, &self.gen_inverse_body())
+    }
+
+    /// The body of this gate's expression, in terms of its parameter.
+    pub(crate) fn gen_body(&self) -> String {
+        subspace_rotation_body(self.radix, self.levels, RZ_BLOCK)
+    }
+
+    /// The body of the inverse's expression, with the parameter negated.
+    pub(crate) fn gen_inverse_body(&self) -> String {
+        negate_params(&self.gen_body(), &["θ"])
+    }
+}
+
+impl UnitaryExpressionGenerator for RZGate {
+    fn gen_expr(&self) -> UnitaryExpression {
+        subspace_rotation_expr("RZ", self.radix, self.levels, &self.gen_body())
+    }
+}
+
+impl HasParams for RZGate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        1
+    }
+}
+
+impl QuditSystem for RZGate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![self.radix]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.radix
+    }
+}
+
+impl QuditGate for RZGate {
+    fn name(&self) -> String {
+        subspace_rotation_name("RZ", self.radix, self.levels)
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec!["θ".to_string()]
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        vec![ParamSpec::half_angle("θ")]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qudit_core::c64;
+    use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+
+    use super::*;
+
+    const THETA: f64 = 0.7;
+
+    #[test]
+    fn test_qubit_rx_gate() {
+        let (s, c) = (THETA / 2.0).sin_cos();
+        let utry: UnitaryMatrix<c64> = RXGate::new(2, (0, 1)).gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [c, c64::new(0.0, -s)],
+            [c64::new(0.0, -s), c],
+        ]);
+    }
+
+    #[test]
+    fn test_qutrit_rx_gate() {
+        let (s, c) = (THETA / 2.0).sin_cos();
+        let utry: UnitaryMatrix<c64> = RXGate::new(3, (0, 2)).gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [c, 0.0, c64::new(0.0, -s)],
+            [0.0, 1.0, 0.0],
+            [c64::new(0.0, -s), 0.0, c],
+        ]);
+    }
+
+    #[test]
+    fn test_qutrit_ry_gate() {
+        let (s, c) = (THETA / 2.0).sin_cos();
+        let utry: UnitaryMatrix<c64> = RYGate::new(3, (1, 2)).gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [1.0, 0.0, 0.0],
+            [0.0, c, -s],
+            [0.0, s, c],
+        ]);
+    }
+
+    #[test]
+    fn test_reversed_levels_ry_gate() {
+        // Swapping the levels reverses the direction of the rotation.
+        let (s, c) = (THETA / 2.0).sin_cos();
+        let utry: UnitaryMatrix<c64> = RYGate::new(2, (1, 0)).gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [c, s],
+            [-s, c],
+        ]);
+    }
+
+    #[test]
+    fn test_qubit_rz_gate() {
+        let (s, c) = (THETA / 2.0).sin_cos();
+        let utry: UnitaryMatrix<c64> = RZGate::new(2, (0, 1)).gen_expr().get_unitary(&[THETA]);
+        crate::assert_unitary_close!(utry, [
+            [c64::new(c, -s), 0.0],
+            [0.0, c64::new(c, s)],
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_repeated_levels() {
+        RZGate::new(3, (1, 1));
+    }
+}
@@ -1,6 +1,11 @@
+use qudit_core::{radices, HasParams, QuditRadices, QuditSystem};
 use qudit_expr::UnitaryExpression;
 use qudit_expr::UnitaryExpressionGenerator;
 
+use crate::utils::remap_params;
+use crate::ParamSpec;
+use crate::QuditGate;
+
 /// The single-qubit U3 gate parameterizes a general single-qubit unitary.
 ///
 /// The U3 gate is given by the following matrix:
@@ -20,13 +25,81 @@ use qudit_expr::UnitaryExpressionGenerator;
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub struct U3Gate;
 
+impl U3Gate {
+    /// Generate the expression for the inverse of this gate.
+    ///
+    /// The inverse is given by $U3(-\theta_0, -\theta_2, -\theta_1)$. The
+    /// returned expression takes the same parameters as this gate.
+    pub fn gen_inverse_expr(&self) -> UnitaryExpression {
+        let proto = "utry U3dg(θ0, θ1, θ2)";
+        UnitaryExpression::new(proto.to_owned() + "{" + &self.gen_inverse_body() + "}")
+    }
+
+    /// The body of this gate's expression, in terms of its parameters.
+    pub(crate) fn gen_body(&self) -> String {
+        "[
+                [cos(θ0/2), ~e^(i*θ2)*sin(θ0/2)],
+                [e^(i*θ1)*sin(θ0/2), e^(i*(θ1+θ2))*cos(θ0/2)]
+        ]".to_string()
+    }
+
+    /// The body of the inverse's expression, with the parameters mapped to
+    /// $(-\theta_0, -\theta_2, -\theta_1)$.
+    pub(crate) fn gen_inverse_body(&self) -> String {
+        remap_params(&self.gen_body(), |param| match param {
+            "θ0" => Some("(~θ0)".to_string()),
+            "θ1" => Some("(~θ2)".to_string()),
+            "θ2" => Some("(~θ1)".to_string()),
+            _ => None,
+        })
+    }
+}
+
 impl UnitaryExpressionGenerator for U3Gate {
     fn gen_expr(&self) -> UnitaryExpression {
         let proto = "utry U3(θ0, θ1, θ2)";
-        let body = "[
-                [cos(θ0/2), ~e^(i*θ2)*sin(θ0/2)],
-                [e^(i*θ1)*sin(θ0/2), e^(i*(θ1+θ2))*cos(θ0/2)]
-        ]";
-        UnitaryExpression::new(proto.to_owned() + "{" + &body + "}")
+        UnitaryExpression::new(proto.to_owned() + "{" + &self.gen_body() + "}")
+    }
+}
+
+impl HasParams for U3Gate {
+    #[inline]
+    fn num_params(&self) -> usize {
+        3
+    }
+}
+
+impl QuditSystem for U3Gate {
+    #[inline]
+    fn radices(&self) -> QuditRadices {
+        radices![2]
+    }
+
+    #[inline]
+    fn num_qudits(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+impl QuditGate for U3Gate {
+    fn name(&self) -> String {
+        "U3".to_string()
+    }
+
+    fn param_names(&self) -> Vec<String> {
+        vec!["θ0".to_string(), "θ1".to_string(), "θ2".to_string()]
+    }
+
+    fn param_specs(&self) -> Vec<ParamSpec> {
+        vec![
+            ParamSpec::half_angle("θ0"),
+            ParamSpec::angle("θ1"),
+            ParamSpec::angle("θ2"),
+        ]
     }
 }
@@ -0,0 +1,505 @@
+use std::f64::consts::PI;
+
+use qudit_core::c64;
+use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+use qudit_core::HasParams;
+use qudit_core::QuditSystem;
+use qudit_expr::UnitaryExpressionGenerator;
+
+use crate::utils::{compress_levels, expand_index, radices_to_vec};
+use crate::Gate;
+
+/// The number of random parameter points inspected when computing the
+/// properties of a parameterized gate.
+const NUM_PROPERTY_SAMPLES: usize = 4;
+
+/// The tolerance used when inspecting a gate's unitary numerically.
+const PROPERTY_TOL: f64 = 1e-8;
+
+/// Algebraic properties of a gate's unitary.
+///
+/// For parameterized gates, a property is only reported if it holds for
+/// every value of the parameters. For example, $P(\theta)$ is diagonal,
+/// but it is not reported as Clifford, even though it is Clifford for
+/// some values of $\theta$.
+///
+/// # Examples
+///
+/// ```
+/// use qudit_gates::Gate;
+///
+/// let props = Gate::X(3).properties();
+/// assert!(props.permutation);
+/// assert!(props.clifford);
+/// assert!(props.weyl_heisenberg);
+/// assert!(!props.self_inverse);
+///
+/// assert!(Gate::P(3).properties().diagonal);
+/// assert!(!Gate::P(3).properties().clifford);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct GateProperties {
+    /// The unitary is a diagonal matrix.
+    pub diagonal: bool,
+
+    /// The unitary is a permutation matrix, with no phases.
+    pub permutation: bool,
+
+    /// The unitary is equal to its conjugate transpose.
+    pub hermitian: bool,
+
+    /// The unitary squares to the identity, up to a global phase.
+    pub self_inverse: bool,
+
+    /// The unitary maps Weyl-Heisenberg operators to Weyl-Heisenberg
+    /// operators under conjugation, up to a global phase.
+    pub clifford: bool,
+
+    /// The unitary is a tensor product of Weyl-Heisenberg operators
+    /// $X^a Z^b$, up to a global phase.
+    pub weyl_heisenberg: bool,
+}
+
+impl Gate {
+    /// Compute the algebraic properties of this gate.
+    ///
+    /// Properties of well-known constant gates are determined from their
+    /// structure. Otherwise, the gate's expression is evaluated and the
+    /// properties are inspected numerically; parameterized gates are
+    /// inspected at several random parameter points.
+    pub fn properties(&self) -> GateProperties {
+        match self.known_properties() {
+            Some(props) => props,
+            None => self.inspect_properties(),
+        }
+    }
+
+    /// Returns true if the gate's unitary is diagonal.
+    #[inline]
+    pub fn is_diagonal(&self) -> bool {
+        self.properties().diagonal
+    }
+
+    /// Returns true if the gate's unitary is a permutation matrix.
+    #[inline]
+    pub fn is_permutation(&self) -> bool {
+        self.properties().permutation
+    }
+
+    /// Returns true if the gate's unitary is Hermitian.
+    #[inline]
+    pub fn is_hermitian(&self) -> bool {
+        self.properties().hermitian
+    }
+
+    /// Returns true if the gate is its own inverse, up to a global phase.
+    #[inline]
+    pub fn is_self_inverse(&self) -> bool {
+        self.properties().self_inverse
+    }
+
+    /// Returns true if the gate is in the Clifford group.
+    #[inline]
+    pub fn is_clifford(&self) -> bool {
+        self.properties().clifford
+    }
+
+    /// Returns true if the gate is in the Weyl-Heisenberg group.
+    #[inline]
+    pub fn is_weyl_heisenberg(&self) -> bool {
+        self.properties().weyl_heisenberg
+    }
+
+    /// The properties of gates whose structure is known at construction.
+    fn known_properties(&self) -> Option<GateProperties> {
+        let props = |diagonal, permutation, hermitian, self_inverse, clifford, weyl_heisenberg| {
+            GateProperties {
+                diagonal,
+                permutation,
+                hermitian,
+                self_inverse,
+                clifford,
+                weyl_heisenberg,
+            }
+        };
+
+        match self {
+            Gate::IGate(_) => Some(props(true, true, true, true, true, true)),
+            Gate::HGate(gate) => {
+                let qubit = gate.radix == 2;
+                Some(props(false, false, qubit, qubit, true, false))
+            },
+            Gate::XGate(gate) => {
+                let qubit = gate.radix == 2;
+                Some(props(false, true, qubit, qubit, true, true))
+            },
+            Gate::ZGate(gate) => {
+                let qubit = gate.radix == 2;
+                Some(props(true, false, qubit, qubit, true, true))
+            },
+            Gate::YGate(_) => Some(props(false, false, true, true, true, true)),
+            Gate::SGate(_) | Gate::SdgGate(_) => {
+                Some(props(true, false, false, false, true, false))
+            },
+            Gate::TGate(_) | Gate::TdgGate(_) => {
+                Some(props(true, false, false, false, false, false))
+            },
+            Gate::SXGate(_) | Gate::SXdgGate(_) => {
+                Some(props(false, false, false, false, true, false))
+            },
+            Gate::SwapGate(gate) if !gate.is_mixed() => {
+                Some(props(false, true, true, true, true, false))
+            },
+            Gate::WeylGate(gate) => {
+                // (X^a Z^b)^2 = ω^(ab) X^(2a) Z^(2b). When 2a = 0 mod d, the
+                // phased gate squares to ω^(2ab) = 1 and the unphased gate
+                // to ω^(ab).
+                let d = gate.radix;
+                let self_inverse = (2 * gate.a) % d == 0 && (2 * gate.b) % d == 0;
+                let hermitian = self_inverse && (gate.phased || (gate.a * gate.b) % d == 0);
+                Some(props(gate.a == 0, gate.b == 0, hermitian, self_inverse, true, true))
+            },
+            Gate::CSumGate(gate) if gate.multiplier == 0 => {
+                Some(props(true, true, true, true, true, true))
+            },
+            Gate::CSumGate(gate) => {
+                // Conjugating the control's shift gives a shift of the target
+                // by k on every level but the last, which wraps around; this
+                // is only a Weyl-Heisenberg operator if k * d_c = 0 mod d_t.
+                let k = gate.multiplier;
+                let involution = (2 * k) % gate.target_radix == 0;
+                let clifford = (k * gate.control_radix) % gate.target_radix == 0;
+                Some(props(false, true, involution, involution, clifford, false))
+            },
+            Gate::PermutationGate(gate) if gate.output_radices() == gate.radices() => {
+                let perm = gate.perm();
+                let identity = perm.iter().enumerate().all(|(i, p)| *p == i);
+                let involution = perm.iter().enumerate().all(|(i, p)| perm[*p] == i);
+                Some(props(identity, true, involution, involution, true, identity))
+            },
+            // Inverting a gate preserves all of its properties.
+            Gate::Dagger(gate) => Some(gate.gate().properties()),
+            Gate::Inverse(gate) => Some(gate.gate().properties()),
+            _ => None,
+        }
+    }
+
+    /// Inspect the properties of this gate's unitary numerically.
+    fn inspect_properties(&self) -> GateProperties {
+        let num_samples = if self.num_params() == 0 { 1 } else { NUM_PROPERTY_SAMPLES };
+        let radices = radices_to_vec(&self.radices());
+        let expr = self.gen_expr();
+        let mut rng = rand::thread_rng();
+
+        let mut props = GateProperties {
+            diagonal: true,
+            permutation: true,
+            hermitian: true,
+            self_inverse: true,
+            clifford: true,
+            weyl_heisenberg: true,
+        };
+
+        for _ in 0..num_samples {
+            let params = self.random_params(&mut rng);
+            let utry: UnitaryMatrix<c64> = expr.get_unitary(&params);
+            let utry = Dense::from_unitary(&utry);
+            props.diagonal &= utry.is_diagonal();
+            props.permutation &= utry.is_permutation();
+            props.hermitian &= utry.is_hermitian();
+            props.self_inverse &= utry.dot(&utry).is_scaled_identity();
+            // The Clifford check is by far the most expensive; skip it once
+            // it has already failed.
+            if props.clifford {
+                props.clifford = utry.is_clifford(&radices);
+            }
+            props.weyl_heisenberg &= utry.is_weyl_heisenberg(&radices);
+        }
+
+        props
+    }
+}
+
+/// A dense, row-major square matrix used for numeric inspection.
+struct Dense {
+    dim: usize,
+    data: Vec<c64>,
+}
+
+impl Dense {
+    fn zeros(dim: usize) -> Self {
+        Dense { dim, data: vec![c64::new(0.0, 0.0); dim * dim] }
+    }
+
+    fn from_unitary(utry: &UnitaryMatrix<c64>) -> Self {
+        let dim = utry.dimension();
+        let mut mat = Dense::zeros(dim);
+        for i in 0..dim {
+            for j in 0..dim {
+                *mat.get_mut(i, j) = utry[(i, j)];
+            }
+        }
+        mat
+    }
+
+    #[inline]
+    fn get(&self, i: usize, j: usize) -> c64 {
+        self.data[i * self.dim + j]
+    }
+
+    #[inline]
+    fn get_mut(&mut self, i: usize, j: usize) -> &mut c64 {
+        &mut self.data[i * self.dim + j]
+    }
+
+    fn dot(&self, other: &Dense) -> Dense {
+        let mut out = Dense::zeros(self.dim);
+        for i in 0..self.dim {
+            for k in 0..self.dim {
+                let a = self.get(i, k);
+                if a.norm() <= PROPERTY_TOL {
+                    continue;
+                }
+                for j in 0..self.dim {
+                    *out.get_mut(i, j) += a * other.get(k, j);
+                }
+            }
+        }
+        out
+    }
+
+    fn adjoint(&self) -> Dense {
+        let mut out = Dense::zeros(self.dim);
+        for i in 0..self.dim {
+            for j in 0..self.dim {
+                *out.get_mut(j, i) = self.get(i, j).conj();
+            }
+        }
+        out
+    }
+
+    fn is_diagonal(&self) -> bool {
+        (0..self.dim).all(|i| {
+            (0..self.dim).all(|j| i == j || self.get(i, j).norm() <= PROPERTY_TOL)
+        })
+    }
+
+    fn is_permutation(&self) -> bool {
+        let one = c64::new(1.0, 0.0);
+        (0..self.dim).all(|j| {
+            let column = (0..self.dim).map(|i| self.get(i, j));
+            let ones = column.clone().filter(|e| (e - one).norm() <= PROPERTY_TOL).count();
+            let zeros = column.filter(|e| e.norm() <= PROPERTY_TOL).count();
+            ones == 1 && zeros == self.dim - 1
+        })
+    }
+
+    fn is_hermitian(&self) -> bool {
+        (0..self.dim).all(|i| {
+            (0..self.dim).all(|j| {
+                (self.get(i, j) - self.get(j, i).conj()).norm() <= PROPERTY_TOL
+            })
+        })
+    }
+
+    fn is_scaled_identity(&self) -> bool {
+        let scale = self.get(0, 0);
+        self.is_diagonal()
+            && (0..self.dim).all(|i| (self.get(i, i) - scale).norm() <= PROPERTY_TOL)
+    }
+
+    /// Returns true if the matrix is $c X^a Z^b$ for some phase $c$ and
+    /// vectors of shifts $a$ and clocks $b$ over `radices`.
+    fn is_weyl_heisenberg(&self, radices: &[usize]) -> bool {
+        // The shift is determined by where the first basis state is sent.
+        let (row0, c0) = match (0..self.dim)
+            .map(|i| (i, self.get(i, 0)))
+            .find(|(_, e)| e.norm() > PROPERTY_TOL)
+        {
+            Some(entry) => entry,
+            None => return false,
+        };
+        let shift = expand_index(row0, radices);
+
+        // The clock of each qudit is determined by the phase picked up by
+        // the basis state with a single excitation on that qudit.
+        let mut clock = vec![0; radices.len()];
+        for (k, radix) in radices.iter().enumerate() {
+            let mut levels = vec![0; radices.len()];
+            levels[k] = 1;
+            let col = compress_levels(&levels, radices);
+            let row = compress_levels(&shifted(&levels, &shift, radices), radices);
+            let phase = self.get(row, col) / c0;
+            let clock_k = (0..*radix)
+                .find(|b| (phase - root_of_unity(*radix, *b)).norm() <= PROPERTY_TOL);
+            match clock_k {
+                Some(b) => clock[k] = b,
+                None => return false,
+            }
+        }
+
+        (0..self.dim).all(|col| {
+            let levels = expand_index(col, radices);
+            let row = compress_levels(&shifted(&levels, &shift, radices), radices);
+            let phase = levels
+                .iter()
+                .zip(clock.iter())
+                .zip(radices.iter())
+                .fold(c0, |acc, ((level, b), radix)| {
+                    acc * root_of_unity(*radix, (level * b) % radix)
+                });
+            (0..self.dim).all(|i| {
+                let expected = if i == row { phase } else { c64::new(0.0, 0.0) };
+                (self.get(i, col) - expected).norm() <= PROPERTY_TOL
+            })
+        })
+    }
+
+    /// Returns true if conjugating the single-qudit shift and clock
+    /// operators by this matrix yields Weyl-Heisenberg operators.
+    fn is_clifford(&self, radices: &[usize]) -> bool {
+        let adjoint = self.adjoint();
+        (0..radices.len()).all(|k| {
+            [weyl_generator(radices, k, true), weyl_generator(radices, k, false)]
+                .iter()
+                .all(|gen| self.dot(gen).dot(&adjoint).is_weyl_heisenberg(radices))
+        })
+    }
+}
+
+/// The shift (`shift = true`) or clock operator acting on qudit `k`.
+fn weyl_generator(radices: &[usize], k: usize, shift: bool) -> Dense {
+    let dim = radices.iter().product();
+    let mut mat = Dense::zeros(dim);
+    for col in 0..dim {
+        let mut levels = expand_index(col, radices);
+        if shift {
+            levels[k] = (levels[k] + 1) % radices[k];
+            *mat.get_mut(compress_levels(&levels, radices), col) = c64::new(1.0, 0.0);
+        } else {
+            *mat.get_mut(col, col) = root_of_unity(radices[k], levels[k]);
+        }
+    }
+    mat
+}
+
+/// $\omega^k$ where $\omega = e^{2\pi i / d}$.
+#[inline]
+fn root_of_unity(radix: usize, k: usize) -> c64 {
+    let theta = 2.0 * PI * k as f64 / radix as f64;
+    c64::new(theta.cos(), theta.sin())
+}
+
+/// Add `shift` to `levels`, modulo each qudit's radix.
+fn shifted(levels: &[usize], shift: &[usize], radices: &[usize]) -> Vec<usize> {
+    levels
+        .iter()
+        .zip(shift.iter())
+        .zip(radices.iter())
+        .map(|((level, s), radix)| (level + s) % radix)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qudit_core::radices;
+
+    fn assert_known_properties_match_inspection(gate: Gate) {
+        let known = gate.known_properties().unwrap();
+        assert_eq!(known, gate.inspect_properties(), "{:?}", gate);
+    }
+
+    #[test]
+    fn test_known_properties_match_inspection() {
+        for radix in 2..=5 {
+            assert_known_properties_match_inspection(Gate::I(radix));
+            assert_known_properties_match_inspection(Gate::H(radix));
+            assert_known_properties_match_inspection(Gate::X(radix));
+            assert_known_properties_match_inspection(Gate::Z(radix));
+        }
+        for radix in 2..=3 {
+            assert_known_properties_match_inspection(Gate::Swap(radix));
+        }
+        assert_known_properties_match_inspection(Gate::Y());
+        assert_known_properties_match_inspection(Gate::S());
+        assert_known_properties_match_inspection(Gate::Sdg());
+        assert_known_properties_match_inspection(Gate::T());
+        assert_known_properties_match_inspection(Gate::Tdg());
+        assert_known_properties_match_inspection(Gate::SX());
+        assert_known_properties_match_inspection(Gate::SXdg());
+
+        for radix in 2..=4 {
+            for a in 0..radix {
+                for b in 0..radix {
+                    assert_known_properties_match_inspection(Gate::Weyl(radix, a, b));
+                    assert_known_properties_match_inspection(Gate::from(
+                        crate::WeylGate::unphased(radix, a, b),
+                    ));
+                }
+            }
+            for target_radix in 2..=4 {
+                for k in 0..target_radix {
+                    assert_known_properties_match_inspection(Gate::from(
+                        crate::CSumGate::new_general(radix, target_radix, k),
+                    ));
+                }
+            }
+        }
+
+        for perm in [vec![0, 1, 2], vec![1, 0, 2], vec![2, 1, 0], vec![1, 2, 0]] {
+            assert_known_properties_match_inspection(Gate::Permutation(radices![2, 2, 2], perm));
+        }
+        assert_known_properties_match_inspection(Gate::Permutation(
+            radices![2, 3, 2],
+            vec![2, 1, 0],
+        ));
+        assert_known_properties_match_inspection(Gate::Permutation(
+            radices![3, 3, 3],
+            vec![1, 2, 0],
+        ));
+        assert!(Gate::Permutation(radices![2, 3], vec![1, 0]).known_properties().is_none());
+    }
+
+    #[test]
+    fn test_weyl_and_csum_properties() {
+        for radix in 2..=4 {
+            for a in 0..radix {
+                for b in 0..radix {
+                    let props = Gate::Weyl(radix, a, b).properties();
+                    assert!(props.weyl_heisenberg);
+                    assert!(props.clifford);
+                    assert_eq!(props.diagonal, a == 0);
+                }
+            }
+
+            let props = Gate::CSUM(radix).properties();
+            assert!(props.permutation);
+            assert!(props.clifford);
+            assert!(!props.weyl_heisenberg);
+            assert_eq!(props.self_inverse, radix == 2);
+        }
+    }
+
+    #[test]
+    fn test_parameterized_properties() {
+        let p = Gate::P(3).properties();
+        assert!(p.diagonal);
+        assert!(!p.clifford);
+        assert!(!p.permutation);
+
+        assert!(Gate::RZZ(2).properties().diagonal);
+        assert_eq!(Gate::U3().properties(), GateProperties::default());
+    }
+
+    #[test]
+    fn test_composed_properties() {
+        assert_eq!(Gate::CX().properties(), Gate::CSUM(2).properties());
+        assert!(Gate::CP().is_diagonal());
+        assert!(!Gate::CP().is_clifford());
+        assert_eq!(
+            Gate::from(crate::DaggerGate::from_gate(crate::TGate)).properties(),
+            Gate::T().properties(),
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! Proptest strategies for generating arbitrary gates.
+//!
+//! This module is available behind the `proptest` cargo feature.
+
+use proptest::prelude::*;
+use proptest::strategy::{BoxedStrategy, Union};
+use qudit_core::{radices, QuditRadices};
+
+use crate::CSumGate;
+use crate::ControlledGate;
+use crate::DaggerGate;
+use crate::Gate;
+use crate::HGate;
+use crate::PGate;
+use crate::SwapGate;
+
+use crate::utils::radices_to_vec;
+
+/// Inclusive bounds on the radices of generated gates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RadixBounds {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl RadixBounds {
+    /// Construct a RadixBounds.
+    ///
+    /// # Panics
+    ///
+    /// * If `min` is less than 2 or greater than `max`.
+    pub fn new(min: usize, max: usize) -> Self {
+        if min < 2 || min > max {
+            panic!("Expected radix bounds to satisfy 2 <= min <= max.");
+        }
+        Self { min, max }
+    }
+
+    /// A strategy generating radices within these bounds.
+    pub fn radix(&self) -> BoxedStrategy<usize> {
+        (self.min..=self.max).boxed()
+    }
+}
+
+impl Default for RadixBounds {
+    fn default() -> Self {
+        Self::new(2, 4)
+    }
+}
+
+/// Gates that can generate strategies for a fixed set of radices.
+pub trait ArbitraryGateWithRadices {
+    /// A strategy generating gates of this kind acting on `radices`.
+    ///
+    /// Returns None if no gate of this kind is compatible with `radices`.
+    fn arbitrary_with_radices(radices: QuditRadices) -> Option<BoxedStrategy<Gate>>;
+
+    /// Like `arbitrary_with_radices`, but without composed gates that wrap
+    /// other generated gates.
+    fn arbitrary_with_radices_no_rec(radices: QuditRadices) -> Option<BoxedStrategy<Gate>>;
+}
+
+/// A strategy for a non-empty set of distinct control levels.
+pub(crate) fn arbitrary_control_levels(radix: usize) -> BoxedStrategy<Vec<usize>> {
+    proptest::sample::subsequence((0..radix).collect::<Vec<usize>>(), 1..=radix).boxed()
+}
+
+/// A strategy for single-qudit gates that wrap no other gate.
+fn arbitrary_leaf_gate(bounds: RadixBounds) -> BoxedStrategy<Gate> {
+    bounds
+        .radix()
+        .prop_flat_map(|radix| {
+            Gate::arbitrary_with_radices_no_rec(radices![radix])
+                .expect("Every single-qudit radix has a gate.")
+        })
+        .boxed()
+}
+
+impl Arbitrary for Gate {
+    type Parameters = RadixBounds;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(bounds: Self::Parameters) -> Self::Strategy {
+        arbitrary_leaf_gate(bounds)
+            .prop_recursive(2, 8, 1, move |inner| {
+                prop_oneof![
+                    inner.clone().prop_map(|gate| Gate::Dagger(DaggerGate::from_gate(gate))),
+                    (inner, bounds.radix())
+                        .prop_flat_map(|(gate, radix)| {
+                            arbitrary_control_levels(radix).prop_map(move |levels| {
+                                Gate::Controlled(ControlledGate::from_gate(
+                                    gate.clone(),
+                                    radices![radix],
+                                    vec![levels],
+                                ))
+                            })
+                        }),
+                ]
+            })
+            .boxed()
+    }
+}
+
+impl ArbitraryGateWithRadices for Gate {
+    fn arbitrary_with_radices(radices: QuditRadices) -> Option<BoxedStrategy<Gate>> {
+        let strategies: Vec<BoxedStrategy<Gate>> = [
+            Gate::arbitrary_with_radices_no_rec(radices.clone()),
+            DaggerGate::arbitrary_with_radices(radices.clone()),
+            ControlledGate::arbitrary_with_radices(radices),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if strategies.is_empty() {
+            None
+        } else {
+            Some(Union::new(strategies).boxed())
+        }
+    }
+
+    fn arbitrary_with_radices_no_rec(radices: QuditRadices) -> Option<BoxedStrategy<Gate>> {
+        let mut strategies: Vec<BoxedStrategy<Gate>> = [
+            HGate::arbitrary_with_radices_no_rec(radices.clone()),
+            PGate::arbitrary_with_radices_no_rec(radices.clone()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        match radices_to_vec(&radices)[..] {
+            [radix] => {
+                strategies.push(Just(Gate::I(radix)).boxed());
+                strategies.push(Just(Gate::X(radix)).boxed());
+                strategies.push(Just(Gate::Z(radix)).boxed());
+                if radix == 2 {
+                    strategies.push(Just(Gate::U3()).boxed());
+                }
+            },
+            [control_radix, target_radix] => {
+                let csum = CSumGate::new_mixed(control_radix, target_radix);
+                strategies.push(Just(Gate::from(csum)).boxed());
+                // A mixed swap's output radices are reversed, so it only
+                // fits these radices if they are equal.
+                if control_radix == target_radix {
+                    strategies.push(Just(Gate::from(SwapGate::new(control_radix))).boxed());
+                    strategies.push(Just(Gate::RZZ(control_radix)).boxed());
+                }
+            },
+            _ => {},
+        }
+
+        if strategies.is_empty() {
+            None
+        } else {
+            Some(Union::new(strategies).boxed())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::radices_from_slice;
+    use qudit_core::QuditSystem;
+
+    proptest! {
+        #[test]
+        fn test_arbitrary_with_radices_matches_radices(
+            radices in proptest::collection::vec(2usize..=4, 1..=3)
+        ) {
+            let radices = radices_from_slice(&radices);
+            let strategy = Gate::arbitrary_with_radices(radices.clone()).unwrap();
+            let mut runner = proptest::test_runner::TestRunner::default();
+            let gate = strategy.new_tree(&mut runner).unwrap().current();
+            prop_assert_eq!(gate.radices(), radices);
+            if let Gate::SwapGate(swap) = &gate {
+                prop_assert!(!swap.is_mixed());
+            }
+        }
+
+        #[test]
+        fn test_arbitrary_gate_radices_in_bounds(gate in any_with::<Gate>(RadixBounds::new(2, 3))) {
+            prop_assert!(gate.radices().iter().all(|radix| (2..=3).contains(&(*radix as usize))));
+        }
+    }
+}
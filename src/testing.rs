@@ -0,0 +1,287 @@
+//! Assertion helpers for testing gates.
+//!
+//! These helpers are always available to this crate's tests and are
+//! exported to downstream crates behind the `testing` cargo feature.
+
+use qudit_core::c32;
+use qudit_core::c64;
+use qudit_core::ComplexScalar;
+use qudit_core::matrix::MatVec;
+use qudit_core::unitary::{DifferentiableUnitaryFn, UnitaryFn, UnitaryMatrix};
+use qudit_core::QuditSystem;
+use qudit_expr::UnitaryExpressionGenerator;
+
+/// Assert that `utry` is unitary, i.e., $U^\dagger U = I$, up to `tol`.
+///
+/// # Panics
+///
+/// * If any entry of $U^\dagger U$ differs from the identity by more than
+///   `tol`.
+pub fn assert_is_unitary(utry: &UnitaryMatrix<c64>, tol: f64) {
+    let dim = utry.dimension();
+    for i in 0..dim {
+        for j in 0..dim {
+            let mut entry = c64::new(0.0, 0.0);
+            for k in 0..dim {
+                entry += utry[(k, i)].conj() * utry[(k, j)];
+            }
+            let expected = c64::new(if i == j { 1.0 } else { 0.0 }, 0.0);
+            assert!(
+                (entry - expected).norm() <= tol,
+                "Expected U^†U to be the identity, found {} at ({}, {}).",
+                entry, i, j,
+            );
+        }
+    }
+}
+
+/// Assert that the gradient of `gate` at `params` matches a central
+/// finite-difference approximation with step size `eps`.
+///
+/// The gradient is computed from the gate's generated expression, and each
+/// entry is compared against
+///
+/// $$
+/// \frac{U(\theta + \epsilon e_k) - U(\theta - \epsilon e_k)}{2\epsilon}
+/// $$
+///
+/// with a tolerance of $10^{-6} + \epsilon^2$, which bounds the truncation
+/// error of the central difference for gates with unit-scale parameters.
+///
+/// # Panics
+///
+/// * If `params` does not have one entry per gate parameter.
+///
+/// * If any gradient entry differs from its finite-difference estimate by
+///   more than the tolerance.
+pub fn assert_gradient_matches_finite_difference<G: UnitaryExpressionGenerator>(
+    gate: &G,
+    params: &[f64],
+    eps: f64,
+) {
+    let expr = gate.gen_expr();
+    assert_eq!(params.len(), expr.num_params(), "Expected one value per parameter.");
+
+    let tol = 1e-6 + eps * eps;
+    let dim = expr.dimension();
+    let grad: MatVec<c64> = expr.get_gradient(params);
+
+    for k in 0..params.len() {
+        let mut plus = params.to_vec();
+        let mut minus = params.to_vec();
+        plus[k] += eps;
+        minus[k] -= eps;
+        let utry_plus: UnitaryMatrix<c64> = expr.get_unitary(&plus);
+        let utry_minus: UnitaryMatrix<c64> = expr.get_unitary(&minus);
+
+        for i in 0..dim {
+            for j in 0..dim {
+                let estimate = (utry_plus[(i, j)] - utry_minus[(i, j)]) / (2.0 * eps);
+                let entry = grad.mat_ref(k)[(i, j)];
+                assert!(
+                    (entry - estimate).norm() <= tol,
+                    "Expected gradient {} at ({}, {}) of parameter {} to be close to {}.",
+                    entry, i, j, k, estimate,
+                );
+            }
+        }
+    }
+}
+
+/// A matrix entry that can be compared as a double-precision complex number.
+///
+/// This is implemented for integer, real and complex scalars, so that
+/// literal matrices can be written with whichever is most natural.
+pub trait ToComplex {
+    fn to_c64(self) -> c64;
+}
+
+impl ToComplex for i32 {
+    #[inline]
+    fn to_c64(self) -> c64 {
+        c64::new(self as f64, 0.0)
+    }
+}
+
+impl ToComplex for i64 {
+    #[inline]
+    fn to_c64(self) -> c64 {
+        c64::new(self as f64, 0.0)
+    }
+}
+
+impl ToComplex for f32 {
+    #[inline]
+    fn to_c64(self) -> c64 {
+        c64::new(self as f64, 0.0)
+    }
+}
+
+impl ToComplex for f64 {
+    #[inline]
+    fn to_c64(self) -> c64 {
+        c64::new(self, 0.0)
+    }
+}
+
+impl ToComplex for c32 {
+    #[inline]
+    fn to_c64(self) -> c64 {
+        c64::new(self.re as f64, self.im as f64)
+    }
+}
+
+impl ToComplex for c64 {
+    #[inline]
+    fn to_c64(self) -> c64 {
+        self
+    }
+}
+
+/// Assert that the entries of `utry` are close to `expected`.
+///
+/// If `up_to_phase` is true, `expected` is first multiplied by the global
+/// phase that best aligns it with `utry`. This is the implementation of
+/// [`assert_unitary_close!`](crate::assert_unitary_close) and
+/// [`assert_unitary_close_up_to_phase!`](crate::assert_unitary_close_up_to_phase);
+/// prefer the macros.
+///
+/// # Panics
+///
+/// * If the dimension of `utry` does not match `expected`.
+///
+/// * If any entry differs from the expected entry by more than `tol`.
+pub fn assert_entries_close<C: ComplexScalar + ToComplex>(
+    utry: &UnitaryMatrix<C>,
+    expected: &[Vec<c64>],
+    tol: f64,
+    up_to_phase: bool,
+) {
+    let dim = utry.dimension();
+    assert_eq!(dim, expected.len(), "Expected a {}x{} matrix.", expected.len(), expected.len());
+    for row in expected.iter() {
+        assert_eq!(dim, row.len(), "Expected a square matrix.");
+    }
+
+    let mut phase = c64::new(1.0, 0.0);
+    if up_to_phase {
+        let mut overlap = c64::new(0.0, 0.0);
+        for i in 0..dim {
+            for j in 0..dim {
+                overlap += expected[i][j].conj() * utry[(i, j)].to_c64();
+            }
+        }
+        if overlap.norm() > 0.0 {
+            phase = overlap / overlap.norm();
+        }
+    }
+
+    for i in 0..dim {
+        for j in 0..dim {
+            let entry = utry[(i, j)].to_c64();
+            let target = phase * expected[i][j];
+            assert!(
+                (entry - target).norm() <= tol,
+                "Expected {} at ({}, {}), found {}.",
+                target, i, j, entry,
+            );
+        }
+    }
+}
+
+/// Assert that two unitary matrices are entry-wise close.
+///
+/// # Panics
+///
+/// * If the dimensions of `a` and `b` differ.
+///
+/// * If any entry of `a` differs from the entry of `b` by more than `tol`.
+pub fn assert_unitaries_close<C: ComplexScalar + ToComplex>(
+    a: &UnitaryMatrix<C>,
+    b: &UnitaryMatrix<C>,
+    tol: f64,
+) {
+    let dim = a.dimension();
+    assert_eq!(dim, b.dimension(), "Expected matrices of the same dimension.");
+    for i in 0..dim {
+        for j in 0..dim {
+            let (x, y) = (a[(i, j)].to_c64(), b[(i, j)].to_c64());
+            assert!(
+                (x - y).norm() <= tol,
+                "Expected {} at ({}, {}), found {}.",
+                y, i, j, x,
+            );
+        }
+    }
+}
+
+/// Assert that a unitary matrix is entry-wise close to a literal matrix.
+///
+/// Entries may be integer, real or complex; see [`ToComplex`]. An optional
+/// third argument overrides the default tolerance of `1e-6`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "testing")] {
+/// use qudit_core::c64;
+/// use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+/// use qudit_expr::UnitaryExpressionGenerator;
+/// use qudit_gates::{assert_unitary_close, Gate};
+///
+/// let utry: UnitaryMatrix<c64> = Gate::Y().gen_expr().get_unitary(&[]);
+/// assert_unitary_close!(utry, [
+///     [0, c64::new(0.0, -1.0)],
+///     [c64::new(0.0, 1.0), 0],
+/// ]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_unitary_close {
+    ($utry:expr, [$([$($entry:expr),* $(,)?]),* $(,)?] $(,)?) => {
+        $crate::assert_unitary_close!($utry, [$([$($entry),*]),*], 1e-6)
+    };
+    ($utry:expr, [$([$($entry:expr),* $(,)?]),* $(,)?], $tol:expr $(,)?) => {
+        $crate::testing::assert_entries_close(
+            &$utry,
+            &[$(vec![$($crate::testing::ToComplex::to_c64($entry)),*]),*],
+            $tol,
+            false,
+        )
+    };
+}
+
+/// Assert that a unitary matrix is entry-wise close to a literal matrix,
+/// up to a global phase.
+///
+/// Entries may be integer, real or complex; see [`ToComplex`]. An optional
+/// third argument overrides the default tolerance of `1e-6`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "testing")] {
+/// use qudit_core::c64;
+/// use qudit_core::unitary::{UnitaryFn, UnitaryMatrix};
+/// use qudit_expr::UnitaryExpressionGenerator;
+/// use qudit_gates::{assert_unitary_close_up_to_phase, Gate};
+///
+/// // The Y gate is iXZ.
+/// let utry: UnitaryMatrix<c64> = Gate::Y().gen_expr().get_unitary(&[]);
+/// assert_unitary_close_up_to_phase!(utry, [[0, -1], [1, 0]]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_unitary_close_up_to_phase {
+    ($utry:expr, [$([$($entry:expr),* $(,)?]),* $(,)?] $(,)?) => {
+        $crate::assert_unitary_close_up_to_phase!($utry, [$([$($entry),*]),*], 1e-6)
+    };
+    ($utry:expr, [$([$($entry:expr),* $(,)?]),* $(,)?], $tol:expr $(,)?) => {
+        $crate::testing::assert_entries_close(
+            &$utry,
+            &[$(vec![$($crate::testing::ToComplex::to_c64($entry)),*]),*],
+            $tol,
+            true,
+        )
+    };
+}
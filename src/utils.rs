@@ -0,0 +1,72 @@
+//! Crate-private helpers for working with radices, basis indices and
+//! expression bodies.
+
+use qudit_core::{radices, QuditRadices};
+
+/// Convert `radices` into a vector of plain radix values.
+pub(crate) fn radices_to_vec(radices: &QuditRadices) -> Vec<usize> {
+    radices.iter().map(|radix| *radix as usize).collect()
+}
+
+/// Build a QuditRadices object from plain radix values.
+///
+/// # Panics
+///
+/// * If `radices` is empty.
+pub(crate) fn radices_from_slice(radices: &[usize]) -> QuditRadices {
+    radices[1..]
+        .iter()
+        .fold(radices![radices[0]], |acc, radix| acc.concat(&radices![*radix]))
+}
+
+/// Expand a basis index into per-qudit levels, first qudit most significant.
+pub(crate) fn expand_index(mut index: usize, radices: &[usize]) -> Vec<usize> {
+    let mut levels = vec![0; radices.len()];
+    for (k, radix) in radices.iter().enumerate().rev() {
+        levels[k] = index % radix;
+        index /= radix;
+    }
+    levels
+}
+
+/// Compress per-qudit levels into a basis index, first qudit most
+/// significant.
+pub(crate) fn compress_levels(levels: &[usize], radices: &[usize]) -> usize {
+    levels.iter().zip(radices.iter()).fold(0, |acc, (level, radix)| acc * radix + level)
+}
+
+/// Replace the parameters of an expression body.
+///
+/// Each identifier in `body` for which `map` returns a replacement is
+/// replaced by it. Identifiers are matched whole, so `θ1` is not replaced
+/// inside `θ10`, and all replacements are made in one pass, so parameters
+/// can be swapped with each other.
+pub(crate) fn remap_params<F>(body: &str, map: F) -> String
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut remapped = String::with_capacity(body.len());
+    let mut ident = String::new();
+    for c in body.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            ident.push(c);
+            continue;
+        }
+        remapped += &map(&ident).unwrap_or_else(|| ident.clone());
+        ident.clear();
+        remapped.push(c);
+    }
+    remapped += &map(&ident).unwrap_or(ident);
+    remapped
+}
+
+/// Negate the parameters `params` of an expression body, so that the body
+/// is evaluated at $-\theta$ wherever it was evaluated at $\theta$.
+pub(crate) fn negate_params<S: AsRef<str>>(body: &str, params: &[S]) -> String {
+    remap_params(body, |ident| {
+        params
+            .iter()
+            .any(|param| param.as_ref() == ident)
+            .then(|| format!("(~{})", ident))
+    })
+}